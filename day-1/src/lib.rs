@@ -0,0 +1,448 @@
+use shared::solver::Solver;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Parse an input file path, counting the number of numeric increases in the file.
+///
+/// # Arguments
+///
+/// * `input_path` - the OS fully qualified path to the file containing the input data.
+/// * `window_size` - the number of lines to include in a sliding comparison
+///
+/// # Returns
+///
+/// The count of lines whose numeric value are greater than the preceding value.
+///
+/// # Examples
+///
+/// ## `window_size = 1`
+///
+/// For example, suppose you had the following input file:
+///
+/// ```text
+/// 199
+/// 200
+/// 208
+/// 210
+/// 200
+/// 207
+/// 240
+/// 269
+/// 260
+/// 263
+/// ```
+/// We count the number of times a line increases from the previous line. (There is no measurement before the first measurement.)
+/// In this example, the changes are as follows:
+///
+/// ```text
+/// 199 (N/A - no previous measurement)
+/// 200 (increased)
+/// 208 (increased)
+/// 210 (increased)
+/// 200 (decreased)
+/// 207 (increased)
+/// 240 (increased)
+/// 269 (increased)
+/// 260 (decreased)
+/// 263 (increased)
+/// ```
+///
+/// In this example, there are 7 lines that are larger than the previous, so we return 7.
+///
+/// ## `window_size = 3`
+///
+/// Considering a sliding window, we can compare sets of lines rather than individual lines:
+///
+/// ```text
+/// 199  A      
+/// 200  A B    
+/// 208  A B C  
+/// 210    B C D
+/// 200  E   C D
+/// 207  E F   D
+/// 240  E F G  
+/// 269    F G H
+/// 260      G H
+/// 263        H
+/// ```
+///
+/// Start by comparing the first and second three-measurement windows.
+/// The measurements in the first window are marked A (199, 200, 208); their sum is 199 + 200 + 208 = 607.
+/// The second window is marked B (200, 208, 210); its sum is 618.
+/// The sum of measurements in the second window is larger than the sum of the first, so this first comparison increased.
+///  
+/// In this example, the sum of each three-measurement window is as follows:
+///
+/// ```text
+/// A: 607 (N/A - no previous sum)
+/// B: 618 (increased)
+/// C: 618 (no change)
+/// D: 617 (decreased)
+/// E: 647 (increased)
+/// F: 716 (increased)
+/// G: 769 (increased)
+/// H: 792 (increased)
+/// ```
+///
+/// Leading to 5 windows with an increase.
+pub fn count_numeric_increases(input_path: &str, window_size: usize) -> i32 {
+    let reader = get_buf_reader(input_path);
+    let numbers = reader.lines().map(|line| {
+        line.expect("Failed to parse line from file.")
+            .parse::<i32>()
+            .expect("Error parsing number from file.")
+    });
+    count_increases_from_iter(numbers, window_size)
+}
+
+/// Count the number of numeric increases in a stream of numbers, which may be unbounded.
+///
+/// Works as `count_numeric_increases`, but reads numbers from any iterator rather than a file.
+///
+/// # Arguments
+///
+/// * `iter` - the numbers to compare.
+/// * `window_size` - the number of values to include in a sliding comparison
+///
+/// # Returns
+///
+/// The count of windows whose sum is greater than the preceding window.
+///
+/// # Panics
+///
+/// If `window_size` is 0, since an empty window has no sum to compare.
+pub fn count_increases_from_iter<I: Iterator<Item = i32>>(iter: I, window_size: usize) -> i32 {
+    check_window_size(window_size);
+    // Read each number into a window, removing stale window elements as we traverse the stream
+    let mut window: VecDeque<i32> = VecDeque::new();
+    let mut count_increases = 0;
+
+    for number in iter {
+        // If the window is the expected size, then we've parsed at least window_size numbers out of the file and can compare
+        if window.len() == window_size {
+            // Get the size of the old window
+            let old_size: i32 = window.iter().sum();
+            // Drop the oldest element, ignoring errors because we know it's there
+            let stale = window.pop_front().unwrap();
+            // Get the size of the new window
+            let new_size: i32 = old_size - stale + number;
+            if new_size > old_size {
+                count_increases += 1;
+            }
+        }
+        // Update the window with the latest value
+        window.push_back(number);
+    }
+    count_increases
+}
+
+#[cfg(test)]
+mod test_count_numeric_increases {
+    use crate::count_numeric_increases;
+
+    #[test]
+    fn example_correct_small_window() {
+        assert_eq!(count_numeric_increases("inputs/example.txt", 1), 7);
+    }
+
+    #[test]
+    fn question_correct_small_window() {
+        assert_eq!(count_numeric_increases("inputs/challenge.txt", 1), 1446);
+    }
+
+    #[test]
+    fn example_correct_med_window() {
+        assert_eq!(count_numeric_increases("inputs/example.txt", 3), 5);
+    }
+
+    #[test]
+    fn question_correct_med_window() {
+        assert_eq!(count_numeric_increases("inputs/challenge.txt", 3), 1486);
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        count_numeric_increases("inputs/noexist.txt", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be at least 1")]
+    fn zero_window_fail() {
+        count_numeric_increases("inputs/example.txt", 0);
+    }
+}
+
+#[cfg(test)]
+mod test_count_increases_from_iter {
+    use crate::count_increases_from_iter;
+
+    #[test]
+    fn example_correct() {
+        let numbers = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(count_increases_from_iter(numbers.clone().into_iter(), 1), 7);
+        assert_eq!(count_increases_from_iter(numbers.into_iter(), 3), 5);
+    }
+
+    #[test]
+    fn unbounded_stream_handled() {
+        // Only take what's needed from an endless stream of alternating values
+        let numbers = [1, 2].into_iter().cycle().take(10);
+        assert_eq!(count_increases_from_iter(numbers, 1), 5);
+    }
+}
+
+/// Parse an input file path of decimal values, counting the number of numeric increases in the file.
+///
+/// Works as `count_numeric_increases`, but window sums only count as an increase if they grow by more than
+/// `epsilon`, so that floating point error doesn't turn equal windows into increases.
+///
+/// # Arguments
+///
+/// * `input_path` - the OS fully qualified path to the file containing the input data.
+/// * `window_size` - the number of lines to include in a sliding comparison
+/// * `epsilon` - the amount a window sum must exceed the preceding sum by to count as an increase
+///
+/// # Returns
+///
+/// The count of lines whose numeric value are greater than the preceding value.
+pub fn count_numeric_increases_f64(input_path: &str, window_size: usize, epsilon: f64) -> i32 {
+    check_window_size(window_size);
+    let reader = get_buf_reader(input_path);
+
+    let mut window: VecDeque<f64> = VecDeque::new();
+    let mut count_increases = 0;
+
+    for line in reader.lines() {
+        let line = line.expect("Failed to parse line from file.");
+        let number = line
+            .parse::<f64>()
+            .expect("Error parsing number from file.");
+
+        if window.len() == window_size {
+            let old_size: f64 = window.iter().sum();
+            window.pop_front();
+            // Sum the new window in full, rather than adjusting the old sum, so equal windows sum the same way
+            let new_size: f64 = window.iter().sum::<f64>() + number;
+            if new_size - old_size > epsilon {
+                count_increases += 1;
+            }
+        }
+        window.push_back(number);
+    }
+    count_increases
+}
+
+#[cfg(test)]
+mod test_count_numeric_increases_f64 {
+    use crate::count_numeric_increases_f64;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            count_numeric_increases_f64("inputs/example.txt", 1, 1e-9),
+            7
+        );
+        assert_eq!(
+            count_numeric_increases_f64("inputs/example.txt", 3, 1e-9),
+            5
+        );
+    }
+
+    #[test]
+    fn decimal_correct() {
+        assert_eq!(
+            count_numeric_increases_f64("inputs/example_decimal.txt", 1, 1e-9),
+            3
+        );
+    }
+
+    #[test]
+    fn decimal_equal_window_correct() {
+        // 0.2 + 0.3 + 0.1 and 0.3 + 0.1 + 0.2 differ by floating point error
+        assert_eq!(
+            count_numeric_increases_f64("inputs/example_decimal.txt", 3, 0.0),
+            2
+        );
+        assert_eq!(
+            count_numeric_increases_f64("inputs/example_decimal.txt", 3, 1e-9),
+            1
+        );
+    }
+}
+
+/// Panic if a window size is too small to hold any numbers.
+fn check_window_size(window_size: usize) {
+    if window_size == 0 {
+        panic!("Window size must be at least 1, got: {}", window_size);
+    }
+}
+
+/// Read every line of an input file as a number.
+fn read_numbers(input_path: &str) -> Vec<i32> {
+    get_buf_reader(input_path)
+        .lines()
+        .map(|line| {
+            line.expect("Failed to parse line from file.")
+                .parse::<i32>()
+                .expect("Error parsing number from file.")
+        })
+        .collect::<Vec<i32>>()
+}
+
+/// Count the number of window sums in a list of numbers that increase from the preceding window sum.
+///
+/// Neighboring windows share all but their first and last numbers, so only those need comparing.
+fn count_window_increases(numbers: &[i32], window_size: usize) -> i32 {
+    numbers
+        .iter()
+        .zip(numbers.iter().skip(window_size))
+        .filter(|(stale, number)| number > stale)
+        .count() as i32
+}
+
+/// Parse an input file path once, counting the number of numeric increases for both a window of 1 and of 3 lines.
+///
+/// # Arguments
+///
+/// * `input_path` - the OS fully qualified path to the file containing the input data.
+///
+/// # Returns
+///
+/// The counts of increases for a `window_size` of 1 and 3, as returned by `count_numeric_increases`.
+pub fn count_both(input_path: &str) -> (i32, i32) {
+    let numbers = read_numbers(input_path);
+    (
+        count_window_increases(&numbers, 1),
+        count_window_increases(&numbers, 3),
+    )
+}
+
+#[cfg(test)]
+mod test_count_both {
+    use crate::count_both;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(count_both("inputs/example.txt"), (7, 5));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(count_both("inputs/challenge.txt"), (1446, 1486));
+    }
+}
+
+/// Parse an input file path, finding the net change between the first and last window sums in the file.
+///
+/// # Arguments
+///
+/// * `input_path` - the OS fully qualified path to the file containing the input data.
+/// * `window_size` - the number of lines to include in each window
+///
+/// # Returns
+///
+/// The last window sum minus the first window sum, or 0 if the file is shorter than a single window.
+///
+/// # Examples
+///
+/// ## `window_size = 3`
+///
+/// Using the example from `count_numeric_increases`, the first window (A) sums to 607 and the last window (H) sums
+/// to 792, for a net change of 185.
+pub fn net_change(input_path: &str, window_size: usize) -> i32 {
+    check_window_size(window_size);
+    let numbers = read_numbers(input_path);
+    if numbers.len() < window_size {
+        return 0;
+    }
+    let first: i32 = numbers[..window_size].iter().sum();
+    let last: i32 = numbers[numbers.len() - window_size..].iter().sum();
+    last - first
+}
+
+#[cfg(test)]
+mod test_net_change {
+    use crate::net_change;
+
+    #[test]
+    fn example_correct_small_window() {
+        assert_eq!(net_change("inputs/example.txt", 1), 64);
+    }
+
+    #[test]
+    fn example_correct_med_window() {
+        assert_eq!(net_change("inputs/example.txt", 3), 185);
+    }
+
+    #[test]
+    fn window_larger_than_input() {
+        assert_eq!(net_change("inputs/example.txt", 11), 0);
+    }
+}
+
+/// Solves both parts of the day through the shared `Solver` interface, using windows of 1 and 3 lines.
+pub struct Day1;
+
+impl Solver for Day1 {
+    fn part1(&self, input_path: &str) -> String {
+        count_numeric_increases(input_path, 1).to_string()
+    }
+
+    fn part2(&self, input_path: &str) -> String {
+        count_numeric_increases(input_path, 3).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_day1 {
+    use crate::Day1;
+    use shared::solver::Solver;
+
+    #[test]
+    fn example_correct() {
+        let solver: &dyn Solver = &Day1;
+        assert_eq!(solver.part1("inputs/example.txt"), "7");
+        assert_eq!(solver.part2("inputs/example.txt"), "5");
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(Day1.part1("inputs/challenge.txt"), "1446");
+        assert_eq!(Day1.part2("inputs/challenge.txt"), "1486");
+    }
+}
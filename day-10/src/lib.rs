@@ -0,0 +1,399 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+const OPENERS: [char; 4] = ['(', '{', '[', '<'];
+const CLOSERS: [char; 4] = [')', '}', ']', '>'];
+const MALFORMED_SCORES: [usize; 4] = [3, 1197, 57, 25137];
+// Autocomplete scores grow by a factor of 5 per character, so use the widest integer available
+const INCOMPLETE_SCORES: [u128; 4] = [1, 3, 2, 4];
+
+/// Errors encountered while scoring a file of (), [], {}, <> characters.
+#[derive(Debug, PartialEq)]
+pub enum ScoreError {
+    /// A line (numbered from 1) needed so many characters to complete that its autocomplete score overflowed.
+    Overflow { line_number: usize },
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScoreError::Overflow { line_number } => write!(
+                f,
+                "Line {} has an autocomplete score too large for a u128",
+                line_number
+            ),
+        }
+    }
+}
+
+impl Error for ScoreError {}
+
+/// The result of checking a single line of (), [], {}, <> characters.
+#[derive(Debug, PartialEq)]
+pub enum LineStatus {
+    /// The line closes a chunk with the wrong character, which is the first such character in the line.
+    Corrupt(char),
+    /// The line leaves chunks unclosed, with the characters needed to close them in order.
+    Incomplete(String),
+    /// Every chunk in the line is closed correctly.
+    Valid,
+}
+
+/// Check a single line of (), [], {}, <> characters for syntax errors and unclosed chunks.
+///
+/// Any characters other than the chunk characters are ignored, so lines can be checked as they're typed.
+///
+/// # Arguments
+///
+/// * `line - The characters to check.
+///
+/// # Returns
+///
+/// The first illegal closing character if the line is corrupt, the characters needed to complete the line if it's
+/// incomplete, or `Valid`.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// `{([(<{}[<>[]}>{[]{[(<()>` is corrupt, expecting `]` but finding `}`, while `[({(<(())[]>[[{[]{<()<>>` is
+/// incomplete, and completed by `}}]])})]`.
+pub fn check_line(line: &str) -> LineStatus {
+    let mut char_deque = VecDeque::new();
+    for c in line.chars() {
+        if let Some(idx) = OPENERS.iter().position(|open| c == *open) {
+            char_deque.push_back(CLOSERS[idx]);
+        } else if CLOSERS.contains(&c) && char_deque.pop_back() != Some(c) {
+            return LineStatus::Corrupt(c);
+        }
+    }
+    if char_deque.is_empty() {
+        return LineStatus::Valid;
+    }
+    LineStatus::Incomplete(char_deque.iter().rev().collect())
+}
+
+#[cfg(test)]
+mod test_check_line {
+    use crate::{check_line, LineStatus};
+
+    #[test]
+    fn corrupt_line() {
+        assert_eq!(
+            check_line("{([(<{}[<>[]}>{[]{[(<()>"),
+            LineStatus::Corrupt('}')
+        );
+    }
+
+    #[test]
+    fn unopened_close_corrupt() {
+        assert_eq!(check_line("()>"), LineStatus::Corrupt('>'));
+    }
+
+    #[test]
+    fn incomplete_line() {
+        assert_eq!(
+            check_line("[({(<(())[]>[[{[]{<()<>>"),
+            LineStatus::Incomplete("}}]])})]".to_string())
+        );
+    }
+
+    #[test]
+    fn valid_line() {
+        assert_eq!(check_line("[<>({}){}[([])<>]]"), LineStatus::Valid);
+    }
+}
+
+/// Return the syntax error score of an illegal closing character.
+fn syntax_error_score(c: char) -> usize {
+    CLOSERS
+        .iter()
+        .position(|close| c == *close)
+        .map(|idx| MALFORMED_SCORES[idx])
+        .unwrap_or_else(|| panic!("Not a closing character: {:?}", c))
+}
+
+/// Return the autocomplete score of the characters needed to complete a line, or None if the score overflows.
+fn autocomplete_score(completion: &str) -> Option<u128> {
+    completion.chars().try_fold(0u128, |score, c| {
+        let idx = CLOSERS
+            .iter()
+            .position(|close| c == *close)
+            .unwrap_or_else(|| panic!("Not a closing character: {:?}", c));
+        score.checked_mul(5)?.checked_add(INCOMPLETE_SCORES[idx])
+    })
+}
+
+#[cfg(test)]
+mod test_scores {
+    use crate::{autocomplete_score, syntax_error_score};
+
+    #[test]
+    fn syntax_error_scored() {
+        assert_eq!(syntax_error_score('}'), 1197);
+        assert_eq!(syntax_error_score('>'), 25137);
+    }
+
+    #[test]
+    fn autocomplete_scored() {
+        assert_eq!(autocomplete_score("}}]])})]"), Some(288957));
+        assert_eq!(autocomplete_score("])}>"), Some(294));
+    }
+
+    #[test]
+    fn autocomplete_overflow_checked() {
+        // 55 `>` characters score 5^55 - 1, just under the range of a u128
+        assert_eq!(autocomplete_score(&">".repeat(55)), Some(5u128.pow(55) - 1));
+        assert_eq!(autocomplete_score(&">".repeat(56)), None);
+    }
+}
+
+/// Return the total syntax error score, and the autocomplete score of each incomplete line in file order - or an
+/// error if an autocomplete score overflows.
+fn line_scores(input_path: &str) -> Result<(usize, Vec<u128>), ScoreError> {
+    let reader = get_buf_reader(input_path);
+    let mut syntax_score = 0;
+    let mut incomplete_scores = Vec::new();
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_idx + 1, e));
+        match check_line(&line) {
+            LineStatus::Corrupt(c) => syntax_score += syntax_error_score(c),
+            LineStatus::Incomplete(completion) => incomplete_scores.push(
+                autocomplete_score(&completion).ok_or(ScoreError::Overflow {
+                    line_number: line_idx + 1,
+                })?,
+            ),
+            LineStatus::Valid => {}
+        }
+    }
+    Ok((syntax_score, incomplete_scores))
+}
+
+/// Return the syntax error score and the "middle" autocomplete score in a given file of (), [], {}, <> characters.
+///
+/// A syntax error is any malformed / unclosed combination of opening and closing characters.
+///
+/// Each malformed line gets a syntax error score based on the first incorrect character, with point values being:
+///  
+///  * ): 3 points.
+///  * ]: 57 points.
+///  * }: 1197 points.
+///  * >: 25137 points.
+///
+/// The overall syntax error score is the sum of the scores for each line, with point values being:
+///
+/// Each incomplete line gets an autocomplete score based on the characters needed to complete the line,
+///
+///  * ): 1 point.
+///  * ]: 2 points.
+///  * }: 3 points.
+///  * >: 4 points.
+///
+/// Starting with a total score of 0, then, for each character, multipling the total score by 5 and increasing the total score by the point value given for the character.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the characters to check.
+///
+/// # Returns
+///
+/// The syntax error score and the middle autocomplete score, or an error if a line's autocomplete score doesn't fit
+/// in a u128.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The syntax lines below have an overall score of 26397, and the middle-most incomplete score of 288957:
+/// ```text
+/// [({(<(())[]>[[{[]{<()<>>
+/// [(()[<>])]({[<{<<[]>>(
+/// {([(<{}[<>[]}>{[]{[(<()>
+/// (((({<>}<{<{<>}{[]{[]{}
+/// [[<[([]))<([[{}[[()]]]
+/// [{[{({}]{}}([{[{{{}}([]
+/// {<[[]]>}<{[{[{[]{()[[[]
+/// [<(<(<(<{}))><([]([]()
+/// <{([([[(<>()){}]>(<<{{
+/// <{([{{}}[<[[[<>{}]]]>[]]
+/// ```
+pub fn solution(input_path: &str) -> Result<(usize, u128), ScoreError> {
+    let (syntax_score, mut incomplete_scores) = line_scores(input_path)?;
+    incomplete_scores.sort();
+    Ok((syntax_score, incomplete_scores[incomplete_scores.len() / 2]))
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, ScoreError};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution("inputs/example.txt"), Ok((26397, 288957)));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt"), Ok((296535, 4245130838)));
+    }
+
+    #[test]
+    fn long_incomplete_line_correct() {
+        // 40 unclosed `<` characters score 5^40 - 1, well past the range of a u64
+        assert_eq!(
+            solution("inputs/example_long.txt"),
+            Ok((0, 9094947017729282379150390624))
+        );
+    }
+
+    #[test]
+    fn longest_incomplete_line_correct() {
+        // 55 unclosed `<` characters score 5^55 - 1, the longest such line that fits in a u128
+        assert_eq!(
+            solution("inputs/example_limit.txt"),
+            Ok((0, 277555756156289135105907917022705078124))
+        );
+    }
+
+    #[test]
+    fn overflowing_incomplete_line_fails() {
+        assert_eq!(
+            solution("inputs/example_overflow.txt"),
+            Err(ScoreError::Overflow { line_number: 1 })
+        );
+    }
+}
+
+/// Return the autocomplete score of each incomplete line in a given file of (), [], {}, <> characters.
+///
+/// Scores are calculated as u128, since they grow by a factor of 5 per character needed to complete the line, and
+/// narrowed to u64 - use `solution` for lines long enough that their score doesn't fit.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the characters to check.
+///
+/// # Returns
+///
+/// The autocomplete score of each incomplete line, in file order - corrupt and valid lines are left out. Panics if a
+/// score doesn't fit in a u64.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Using the example lines from `solution`, the incomplete lines score 288957, 5566, 1480781, 995444, and 294, in
+/// that order - the middle of these is 288957.
+pub fn incomplete_scores(input_path: &str) -> Vec<u64> {
+    line_scores(input_path)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .1
+        .into_iter()
+        .map(|score| {
+            u64::try_from(score)
+                .unwrap_or_else(|_| panic!("Autocomplete score doesn't fit in a u64: {}", score))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_incomplete_scores {
+    use crate::incomplete_scores;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            incomplete_scores("inputs/example.txt"),
+            vec![288957, 5566, 1480781, 995444, 294]
+        );
+    }
+
+    #[test]
+    fn question_middle_correct() {
+        let mut scores = incomplete_scores("inputs/challenge.txt");
+        scores.sort();
+        assert_eq!(scores[scores.len() / 2], 4245130838);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Autocomplete score doesn't fit in a u64: 9094947017729282379150390624"
+    )]
+    fn long_line_overflow_fails() {
+        incomplete_scores("inputs/example_long.txt");
+    }
+}
+
+/// Count the corrupt, incomplete, and valid lines in a given file of (), [], {}, <> characters.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the characters to check.
+///
+/// # Returns
+///
+/// The number of corrupt lines, the number of incomplete lines, and the number of valid lines.
+pub fn classify(input_path: &str) -> (usize, usize, usize) {
+    let reader = get_buf_reader(input_path);
+    let (mut corrupt, mut incomplete, mut valid) = (0, 0, 0);
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_idx + 1, e));
+        match check_line(&line) {
+            LineStatus::Corrupt(_) => corrupt += 1,
+            LineStatus::Incomplete(_) => incomplete += 1,
+            LineStatus::Valid => valid += 1,
+        }
+    }
+    (corrupt, incomplete, valid)
+}
+
+#[cfg(test)]
+mod test_classify {
+    use crate::classify;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(classify("inputs/example.txt"), (5, 5, 0));
+    }
+
+    #[test]
+    fn long_incomplete_line_correct() {
+        assert_eq!(classify("inputs/example_long.txt"), (0, 1, 0));
+    }
+}
@@ -0,0 +1,523 @@
+use shared::grid::Grid;
+use shared::parse::parse_digit_line;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Error;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// The energy level an octopus must exceed to flash.
+pub const DEFAULT_ACTIVATION_ENERGY: usize = 9;
+
+/// The most iterations to process while waiting for the flashes to synchronize.
+pub const DEFAULT_SYNC_BUDGET: usize = 1000;
+
+/// A grid of octopus energy levels.
+pub struct Field {
+    spaces: Vec<usize>,
+    width: usize,
+}
+
+impl From<Grid<usize>> for Field {
+    fn from(grid: Grid<usize>) -> Field {
+        Field {
+            spaces: grid.values,
+            width: grid.width,
+        }
+    }
+}
+impl Field {
+    /// Read a field of initial energy levels from an input file.
+    pub fn from_path(input_path: &str) -> Field {
+        let reader = get_buf_reader(input_path);
+        let mut lines = reader.lines().enumerate();
+        let mut inputs = Vec::new();
+        // Parse just the first line to determine the overall width of the inputs
+        let (_, first) = lines.next().expect("No lines found in input file.");
+        inputs.extend(Field::parse_line(1, first));
+        let array_width = inputs.len();
+        let mut field = Field {
+            width: array_width,
+            spaces: inputs,
+        };
+
+        // Parse the remaining lines
+        for (line_idx, line) in lines {
+            field.parse_line_into(line_idx + 1, line);
+        }
+        field
+    }
+
+    /// Return the count of elements in the Field.
+    fn len(&self) -> usize {
+        self.spaces.len()
+    }
+
+    /// Return the indexes of all points adjacent to the given point.
+    fn neighbors(&self, idx: usize) -> Vec<usize> {
+        let mut neighbors = Vec::new();
+        let has_above = idx >= self.width;
+        let has_left = !idx.is_multiple_of(self.width);
+        let has_right = idx % self.width != self.width - 1;
+        let has_below = idx < self.spaces.len() - self.width;
+
+        // Check the value above us
+        if has_above {
+            neighbors.push(idx - self.width);
+        }
+        // Check the value to the left of us
+        if has_left {
+            neighbors.push(idx - 1);
+        }
+        // Check the value to the right of us
+        if has_right {
+            neighbors.push(idx + 1);
+        }
+        // Check the value below us
+        if has_below {
+            neighbors.push(idx + self.width);
+        }
+        // Check top left
+        if has_above && has_left {
+            neighbors.push(idx - 1 - self.width)
+        }
+        // Check top right
+        if has_above && has_right {
+            neighbors.push(idx + 1 - self.width)
+        }
+        // Check bottom left
+        if has_below && has_left {
+            neighbors.push(idx - 1 + self.width)
+        }
+        // Check bottom right
+        if has_below && has_right {
+            neighbors.push(idx + 1 + self.width)
+        }
+        neighbors
+    }
+
+    /// Parse a line of values into a vector for the field
+    fn parse_line(line_number: usize, line: Result<String, Error>) -> Vec<usize> {
+        let line = line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_number, e));
+        parse_digit_line::<usize>(&line)
+            .unwrap_or_else(|_| panic!("Failed to parse line {}: {}", line_number, line))
+    }
+
+    /// Parse a line of values into a vector for the field
+    fn parse_line_into(&mut self, line_number: usize, line: Result<String, Error>) {
+        self.spaces.extend(Field::parse_line(line_number, line));
+    }
+
+    /// Increase the energy of all nodes by one.
+    fn increase_total_energy(&mut self) {
+        for idx in 0..self.len() {
+            self.spaces[idx] += 1;
+        }
+    }
+
+    /// Try to acticate the given node - if it activates, increase neighbors energy and try their activations as well.
+    fn try_activate_node(
+        &mut self,
+        idx: usize,
+        threshold: usize,
+        activations: &mut HashSet<usize>,
+    ) {
+        // If we've already triggered this node, or it's not ready to trigger, move one
+        if self.spaces[idx] <= threshold || activations.contains(&idx) {
+            return;
+        }
+
+        // Activate this node, and all adjascent nodes
+        activations.insert(idx);
+        for neighbor in self.neighbors(idx) {
+            // Since this node activated, the neighbor increases energy
+            self.spaces[neighbor] += 1;
+            // See if we can activate the neighbor now
+            self.try_activate_node(neighbor, threshold, activations);
+        }
+    }
+
+    /// Trigger activation of all available nodes in the field.
+    fn try_activate_all(&mut self, threshold: usize, activations: &mut HashSet<usize>) {
+        for idx in 0..self.len() {
+            self.try_activate_node(idx, threshold, activations);
+        }
+    }
+
+    /// Set a node's energy to 0.
+    fn deactivate_node(&mut self, idx: usize) {
+        self.spaces[idx] = 0;
+    }
+
+    /// Run a single step of the field, returning the number of nodes that flashed.
+    ///
+    /// Nodes flash once their energy exceeds `threshold`, and reset to 0 afterwards.
+    pub fn step(&mut self, threshold: usize) -> usize {
+        self.step_flashes(threshold).len()
+    }
+
+    /// Run a single step of the field, returning the indexes of the nodes that flashed in ascending order.
+    pub fn step_flashes(&mut self, threshold: usize) -> Vec<usize> {
+        let mut activations = HashSet::new();
+        self.increase_total_energy();
+        self.try_activate_all(threshold, &mut activations);
+        let mut flashed = activations.into_iter().collect::<Vec<usize>>();
+        flashed.sort();
+        for idx in &flashed {
+            self.deactivate_node(*idx);
+        }
+        flashed
+    }
+
+    /// Render the energy levels of the field as a grid, one row per line.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        for row in self.spaces.chunks(self.width) {
+            for energy in row {
+                rendered.push_str(&energy.to_string());
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    /// Count the nodes at each energy level from 0 to 9, counting any energy above 9 as 9.
+    pub fn energy_histogram(&self) -> [usize; 10] {
+        let mut histogram = [0; 10];
+        for energy in &self.spaces {
+            histogram[(*energy).min(9)] += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod test_field {
+    use crate::{Field, DEFAULT_ACTIVATION_ENERGY};
+    use shared::grid::Grid;
+
+    fn field(grid: &str) -> Field {
+        grid.parse::<Grid<usize>>().unwrap().into()
+    }
+
+    #[test]
+    fn step_flashes_spread() {
+        let mut field = field("11111\n19991\n19191\n19991\n11111");
+        // The ring of 9s flashes, which then flashes the center
+        assert_eq!(
+            field.step_flashes(DEFAULT_ACTIVATION_ENERGY),
+            vec![6, 7, 8, 11, 12, 13, 16, 17, 18]
+        );
+        assert_eq!(field.render(), "34543\n40004\n50005\n40004\n34543\n");
+        assert_eq!(field.step_flashes(DEFAULT_ACTIVATION_ENERGY), vec![]);
+    }
+
+    #[test]
+    fn render_matches_input() {
+        let contents = std::fs::read_to_string("inputs/example.txt").unwrap();
+        let rendered = Field::from_path("inputs/example.txt").render();
+        assert_eq!(
+            rendered.lines().collect::<Vec<&str>>(),
+            contents.lines().collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn step_flashes() {
+        let mut field = Field::from_path("inputs/example.txt");
+        assert_eq!(field.step(DEFAULT_ACTIVATION_ENERGY), 0);
+        assert_eq!(field.step(DEFAULT_ACTIVATION_ENERGY), 35);
+    }
+
+    #[test]
+    fn energy_histogram_counts() {
+        let mut field = Field::from_path("inputs/example.txt");
+        assert_eq!(
+            field.energy_histogram(),
+            [0, 14, 10, 10, 17, 15, 12, 9, 13, 0]
+        );
+        // After the second step 35 octopuses flashed and reset to 0
+        field.step(DEFAULT_ACTIVATION_ENERGY);
+        field.step(DEFAULT_ACTIVATION_ENERGY);
+        assert_eq!(field.energy_histogram()[0], 35);
+        assert_eq!(field.energy_histogram().iter().sum::<usize>(), 100);
+    }
+}
+
+/// Predict the number of flashes in a population of dumbo octopuses after N iterations, and the time to flash synchronization.
+///
+/// Each octopus flashes based on its energy level. The energy level of each octopus is a
+/// value between 0 and 9.
+///
+/// The energy levels operate in steps, during a single step, the following occurs:
+///  
+///  * First, the energy level of each octopus increases by 1.
+///  * Then, any octopus with an energy level greater than 9 flashes.
+///    * This increases the energy level of all adjacent octopuses by 1, including octopuses that are diagonally adjacent.
+///    * If this causes an octopus to have an energy level greater than 9, it also flashes.
+///    * This process continues as long as new octopuses keep having their energy level increased beyond 9. (An octopus can only flash at most once per step.)
+///  * Finally, any octopus that flashed during this step has its energy level set to 0, as it used all of its energy to flash.
+///  
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial energy levels.
+/// * `num_iterations - The number of iterations to process.
+/// * `threshold - The energy level an octopus must exceed to flash, normally `DEFAULT_ACTIVATION_ENERGY`.
+/// * `max_steps - The most iterations to process while waiting for the flashes to synchronize, normally
+///   `DEFAULT_SYNC_BUDGET`.
+///
+/// # Returns
+///
+/// The total number of flashes after N iterations, as well as the number of iterations it would take to synchronize all flashes at once.
+/// The synchronization iteration is None if that didn't happen within `max_steps` iterations, as some grids and
+/// thresholds never synchronize.
+pub fn solution(
+    input_path: &str,
+    num_iterations: usize,
+    threshold: usize,
+    max_steps: usize,
+) -> (usize, Option<usize>) {
+    let mut field = Field::from_path(input_path);
+
+    let mut flash_counts = Vec::new();
+    let mut sync_step = None;
+    // Lower thresholds can synchronize before all iterations are counted, so keep going until both are found
+    while flash_counts.len() < num_iterations
+        || (sync_step.is_none() && flash_counts.len() < max_steps)
+    {
+        let step_activations = field.step(threshold);
+        flash_counts.push(step_activations);
+        if sync_step.is_none() && step_activations == field.len() {
+            sync_step = Some(flash_counts.len());
+        }
+    }
+    let activation_count = flash_counts.iter().take(num_iterations).sum();
+    (activation_count, sync_step)
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, DEFAULT_ACTIVATION_ENERGY, DEFAULT_SYNC_BUDGET};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution(
+                "inputs/example.txt",
+                100,
+                DEFAULT_ACTIVATION_ENERGY,
+                DEFAULT_SYNC_BUDGET
+            ),
+            (1656, Some(195))
+        );
+    }
+
+    #[test]
+    fn example_lower_threshold_correct() {
+        assert_eq!(
+            solution("inputs/example.txt", 100, 4, DEFAULT_SYNC_BUDGET),
+            (2000, Some(1))
+        );
+    }
+
+    #[test]
+    fn example_budget_exhausted() {
+        // Flashes are still counted for every iteration, even past the budget
+        assert_eq!(
+            solution("inputs/example.txt", 100, DEFAULT_ACTIVATION_ENERGY, 194),
+            (1656, None)
+        );
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            solution(
+                "inputs/challenge.txt",
+                100,
+                DEFAULT_ACTIVATION_ENERGY,
+                DEFAULT_SYNC_BUDGET
+            ),
+            (1613, Some(510))
+        );
+    }
+}
+
+/// Count the flashes in a population of dumbo octopuses during each of N iterations.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial energy levels.
+/// * `num_iterations - The number of iterations to process.
+/// * `threshold - The energy level an octopus must exceed to flash, normally `DEFAULT_ACTIVATION_ENERGY`.
+///
+/// # Returns
+///
+/// The number of flashes during each iteration, in order.
+pub fn flash_counts(input_path: &str, num_iterations: usize, threshold: usize) -> Vec<usize> {
+    let mut field = Field::from_path(input_path);
+    (0..num_iterations).map(|_| field.step(threshold)).collect()
+}
+
+#[cfg(test)]
+mod test_flash_counts {
+    use crate::{flash_counts, DEFAULT_ACTIVATION_ENERGY};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            flash_counts("inputs/example.txt", 10, DEFAULT_ACTIVATION_ENERGY),
+            vec![0, 35, 45, 16, 8, 1, 7, 24, 39, 29]
+        );
+    }
+
+    #[test]
+    fn example_sums_to_solution() {
+        let counts = flash_counts("inputs/example.txt", 100, DEFAULT_ACTIVATION_ENERGY);
+        assert_eq!(counts.iter().sum::<usize>(), 1656);
+    }
+}
+
+/// Find the octopuses that flash during each of N iterations, for replaying the flashes.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial energy levels.
+/// * `num_iterations - The number of iterations to process.
+/// * `threshold - The energy level an octopus must exceed to flash, normally `DEFAULT_ACTIVATION_ENERGY`.
+///
+/// # Returns
+///
+/// The indexes (row by row, with 0 being the top left) of the octopuses that flashed during each iteration, in order.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// In the grid below, the ring of 9s flashes during the first iteration, which in turn flashes the center. Nothing
+/// flashes during the second iteration.
+///
+/// ```text
+/// 11111
+/// 19991
+/// 19191
+/// 19991
+/// 11111
+/// ```
+pub fn flash_events(input_path: &str, num_iterations: usize, threshold: usize) -> Vec<Vec<usize>> {
+    let mut field = Field::from_path(input_path);
+    (0..num_iterations)
+        .map(|_| field.step_flashes(threshold))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_flash_events {
+    use crate::{flash_counts, flash_events, DEFAULT_ACTIVATION_ENERGY};
+
+    #[test]
+    fn example_small_correct() {
+        assert_eq!(
+            flash_events("inputs/example_small.txt", 2, DEFAULT_ACTIVATION_ENERGY),
+            vec![vec![6, 7, 8, 11, 12, 13, 16, 17, 18], vec![]]
+        );
+    }
+
+    #[test]
+    fn example_matches_counts() {
+        let counts = flash_events("inputs/example.txt", 10, DEFAULT_ACTIVATION_ENERGY)
+            .iter()
+            .map(|flashed| flashed.len())
+            .collect::<Vec<usize>>();
+        assert_eq!(
+            counts,
+            flash_counts("inputs/example.txt", 10, DEFAULT_ACTIVATION_ENERGY)
+        );
+    }
+}
+
+/// Find the first iteration during which every octopus in a population flashes at once, giving up after a budget of
+/// iterations.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial energy levels.
+/// * `threshold - The energy level an octopus must exceed to flash, normally `DEFAULT_ACTIVATION_ENERGY`.
+/// * `max_steps - The most iterations to process while waiting for the flashes to synchronize.
+///
+/// # Returns
+///
+/// The iteration (starting from 1) during which every octopus flashed, or None if that didn't happen within
+/// `max_steps` iterations.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The example grid from `solution` synchronizes during iteration 195, so a budget of 194 iterations returns None.
+pub fn first_sync_step(input_path: &str, threshold: usize, max_steps: usize) -> Option<usize> {
+    let mut field = Field::from_path(input_path);
+    (1..=max_steps).find(|_| field.step(threshold) == field.len())
+}
+
+#[cfg(test)]
+mod test_first_sync_step {
+    use crate::{first_sync_step, DEFAULT_ACTIVATION_ENERGY};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            first_sync_step("inputs/example.txt", DEFAULT_ACTIVATION_ENERGY, 1000),
+            Some(195)
+        );
+        assert_eq!(
+            first_sync_step("inputs/example.txt", DEFAULT_ACTIVATION_ENERGY, 194),
+            None
+        );
+    }
+
+    #[test]
+    fn small_budget_exhausted() {
+        // The ring of 9s flashes first, leaving the grid out of step until iteration 6
+        assert_eq!(
+            first_sync_step("inputs/example_small.txt", DEFAULT_ACTIVATION_ENERGY, 5),
+            None
+        );
+        assert_eq!(
+            first_sync_step("inputs/example_small.txt", DEFAULT_ACTIVATION_ENERGY, 6),
+            Some(6)
+        );
+    }
+}
@@ -1,7 +1,7 @@
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::Error;
-use std::io::{BufRead, BufReader};
+use day_11::{solution, Field, DEFAULT_ACTIVATION_ENERGY, DEFAULT_SYNC_BUDGET};
+use shared::args::{parse_part, parse_time};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
 
 /// Parse the file path from command line arguments.
 ///
@@ -11,271 +11,158 @@ use std::io::{BufRead, BufReader};
 ///
 /// # Returns
 ///
-/// A single command line argument - panics if zero or more than one argument are passed.
-fn parse_file_path(args: &[String]) -> &str {
-    if args.len() != 2 {
+/// A file path argument, where `-` reads from stdin, and whether the optional `--animate` flag was passed - panics if
+/// zero or more than two arguments are passed, or the second argument is not `--animate`.
+fn parse_file_path(args: &[String]) -> (&str, bool) {
+    if !(args.len() == 2 || args.len() == 3) {
         panic!(
-            "Expected one file path and an optional window size to run against, got: {} arguments",
+            "Expected one file path and an optional --animate flag to run against, got: {} arguments",
             args.len() - 1
         );
     }
     let input_path = &args[1];
-    input_path.as_str()
-}
-
-#[cfg(test)]
-mod test_parse_file_path {
-    use crate::parse_file_path;
-
-    #[test]
-    fn one_arg_ok() {
-        assert_eq!(
-            parse_file_path(&vec!["script_path".to_string(), "arg_text".to_string()][..]),
-            "arg_text"
-        );
+    if args.len() == 2 {
+        return (input_path.as_str(), false);
     }
-
-    #[test]
-    #[should_panic]
-    fn no_arg_fail() {
-        parse_file_path(&Vec::new());
-    }
-
-    #[test]
-    #[should_panic]
-    fn many_arg_fail() {
-        parse_file_path(
-            &vec![
-                "script_path".to_string(),
-                "arg_text".to_string(),
-                "extra_arg".to_string(),
-            ][..],
-        );
+    if args[2] != "--animate" {
+        panic!("Unknown argument: {}", args[2]);
     }
+    (input_path.as_str(), true)
 }
 
-/// Open an input path and return a buffered reader over the contents.
-fn get_buf_reader(input_path: &str) -> BufReader<File> {
-    // Create a buffer to read the file line by line
-    let contents =
-        File::open(input_path).expect(format!("Error reading file: {}", input_path).as_str());
-    let reader = BufReader::new(contents);
-    reader
-}
-
-#[cfg(test)]
-mod test_get_buf_reader {
-    use crate::get_buf_reader;
-
-    #[test]
-    #[should_panic]
-    fn error_file_handled() {
-        get_buf_reader("inputs/noexist.txt");
-    }
-
-    #[test]
-    fn example_file_handled() {
-        get_buf_reader("inputs/example.txt");
-    }
-}
-
-struct Field {
-    spaces: Vec<usize>,
-    width: usize,
-}
-static ACTIVATION_ENERGY: usize = 9;
-impl Field {
-    /// Return the count of elements in the Field.
-    fn len(&self) -> usize {
-        self.spaces.len()
-    }
-
-    /// Return the indexes of all points adjacent to the given point.
-    fn neighbors(&self, idx: usize) -> Vec<usize> {
-        let mut neighbors = Vec::new();
-        let has_above = idx >= self.width;
-        let has_left = idx % self.width != 0;
-        let has_right = idx % self.width != self.width - 1;
-        let has_below = idx < self.spaces.len() - self.width;
-
-        // Check the value above us
-        if has_above {
-            neighbors.push(idx - self.width);
-        }
-        // Check the value to the left of us
-        if has_left {
-            neighbors.push(idx - 1);
-        }
-        // Check the value to the right of us
-        if has_right {
-            neighbors.push(idx + 1);
-        }
-        // Check the value below us
-        if has_below {
-            neighbors.push(idx + self.width);
-        }
-        // Check top left
-        if has_above && has_left {
-            neighbors.push(idx - 1 - self.width)
-        }
-        // Check top right
-        if has_above && has_right {
-            neighbors.push(idx + 1 - self.width)
-        }
-        // Check bottom left
-        if has_below && has_left {
-            neighbors.push(idx - 1 + self.width)
-        }
-        // Check bottom right
-        if has_below && has_right {
-            neighbors.push(idx + 1 + self.width)
-        }
-        neighbors
-    }
-
-    /// Parse a line of values into a vector for the field
-    fn parse_line(line: Result<String, Error>) -> Vec<usize> {
-        line.expect("Failed to parse line from file.")
-            .split("")
-            .filter(|s| s != &"")
-            .map(|s| {
-                s.parse::<usize>()
-                    .expect("Failed to parse integer from inputs.")
-            })
-            .collect::<Vec<usize>>()
-    }
-
-    /// Parse a line of values into a vector for the field
-    fn parse_line_into(&mut self, line: Result<String, Error>) {
-        self.spaces.extend(Field::parse_line(line));
-    }
-
-    /// Increase the energy of all nodes by one.
-    fn increase_total_energy(&mut self) {
-        for idx in 0..self.len() {
-            self.spaces[idx] += 1;
-        }
-    }
-
-    /// Try to acticate the given node - if it activates, increase neighbors energy and try their activations as well.
-    fn try_activate_node(&mut self, idx: usize, activations: &mut HashSet<usize>) {
-        // If we've already triggered this node, or it's not ready to trigger, move one
-        if self.spaces[idx] <= ACTIVATION_ENERGY || activations.contains(&idx) {
-            return;
-        }
-
-        // Activate this node, and all adjascent nodes
-        activations.insert(idx);
-        for neighbor in self.neighbors(idx) {
-            // Since this node activated, the neighbor increases energy
-            self.spaces[neighbor] += 1;
-            // See if we can activate the neighbor now
-            self.try_activate_node(neighbor, activations);
-        }
-    }
-
-    /// Trigger activation of all available nodes in the field.
-    fn try_activate_all(&mut self, activations: &mut HashSet<usize>) {
-        for idx in 0..self.len() {
-            self.try_activate_node(idx, activations);
-        }
-    }
-
-    /// Set a node's energy to 0.
-    fn deactivate_node(&mut self, idx: usize) {
-        self.spaces[idx] = 0;
-    }
-}
-
-/// Predict the number of flashes in a population of dumbo octopuses after N iterations, and the time to flash synchronization.
-///
-/// Each octopus flashes based on its energy level. The energy level of each octopus is a
-/// value between 0 and 9.
-///
-/// The energy levels operate in steps, during a single step, the following occurs:
-///  
-///  * First, the energy level of each octopus increases by 1.
-///  * Then, any octopus with an energy level greater than 9 flashes.
-///    * This increases the energy level of all adjacent octopuses by 1, including octopuses that are diagonally adjacent.
-///    * If this causes an octopus to have an energy level greater than 9, it also flashes.
-///    * This process continues as long as new octopuses keep having their energy level increased beyond 9. (An octopus can only flash at most once per step.)
-///  * Finally, any octopus that flashed during this step has its energy level set to 0, as it used all of its energy to flash.
-///  
-/// # Arguments
-///
-/// * `input_path - The input file path containing initial energy levels.
-/// * `num_iterations - The number of iterations to process.
-///
-/// # Returns
-///
-/// The total number of flashes after N iterations, as well as the number of iterations it would take to synchronize all flashes at once.
-fn solution(input_path: &str, num_iterations: usize) -> (usize, usize) {
-    let reader = get_buf_reader(input_path);
-    let mut lines = reader.lines();
-    let mut inputs = Vec::new();
-    // Parse just the first line to determine the overall width of the inputs
-    inputs.extend(Field::parse_line(lines.next().expect("")));
-    let array_width = inputs.len();
-    let mut field = Field {
-        width: array_width,
-        spaces: inputs,
-    };
-
-    // Parse the remaining lines
-    while let Some(line) = lines.next() {
-        field.parse_line_into(line);
-    }
-
-    let mut activation_count = 0;
+/// Print the field after each step, until every octopus flashes at once.
+fn animate(input_path: &str) {
+    let mut field = Field::from_path(input_path);
+    println!("Step 0:\n{}", field.render());
     let mut step_num = 0;
     loop {
+        sleep(Duration::from_millis(100));
         step_num += 1;
-        let mut activations = HashSet::new();
-        field.increase_total_energy();
-        field.try_activate_all(&mut activations);
-        if step_num <= num_iterations {
-            activation_count += activations.len();
-        }
-        if activations.len() == field.len() {
-            return (activation_count, step_num);
-        }
-        for idx in activations {
-            field.deactivate_node(idx);
+        field.step(DEFAULT_ACTIVATION_ENERGY);
+        let rendered = field.render();
+        println!("Step {}:\n{}", step_num, rendered);
+        // Every octopus flashed this step when all energy levels were reset to 0
+        if rendered.chars().all(|c| c == '0' || c == '\n') {
+            return;
         }
     }
 }
 
 /// Print the total number of octopi activations after 100 steps, given an input of initial energy levels.
 ///
+/// Pass `--part 1` or `--part 2` to run a single part, otherwise both parts are run.
+///
+/// Pass `--time` to also print how long solving took.
+///
 /// Usage:
 ///
 /// ```
-/// $ aoc inputs/example.txt
+/// $ day-11 inputs/example.txt
 /// Total activation count after 100 steps: 1656
 /// Steps to flash synchronization: 195
 /// ```
+///
+/// Passing `--animate` first prints the energy levels after each step, up to flash synchronization:
+///
+/// ```
+/// $ day-11 inputs/example.txt --animate
+/// Step 0:
+/// 5483143223
+/// ...
+/// ```
 fn main() {
     let args: Vec<String> = std::env::args().collect();
-    let input_path = parse_file_path(&args);
-    let (activation_count, sync_step_count) = solution(input_path, 100);
-    println!(
-        "Total activation count after 100 steps: {:?}",
-        activation_count
+    let (args, part) = parse_part(&args, &[1, 2]).unwrap_or_else(|e| panic!("{}", e));
+    let (args, time) = parse_time(&args);
+    let (input_path, animate_steps) = parse_file_path(&args);
+    if animate_steps {
+        animate(input_path);
+    }
+    let start = Instant::now();
+    let (activation_count, sync_step_count) = solution(
+        input_path,
+        100,
+        DEFAULT_ACTIVATION_ENERGY,
+        DEFAULT_SYNC_BUDGET,
     );
-    println!("Steps to flash synchronization: {:?}", sync_step_count);
+    if part != Some(2) {
+        println!(
+            "Total activation count after 100 steps: {:?}",
+            activation_count
+        );
+    }
+    if part != Some(1) {
+        match sync_step_count {
+            Some(sync_step_count) => {
+                println!("Steps to flash synchronization: {:?}", sync_step_count)
+            }
+            None => println!(
+                "Flashes did not synchronize within {} steps",
+                DEFAULT_SYNC_BUDGET
+            ),
+        }
+    }
+    if time {
+        println!("Elapsed: {:?}", start.elapsed());
+    }
 }
 
 #[cfg(test)]
-mod test_solution {
-    use crate::solution;
+mod test_parse_file_path {
+    use crate::parse_file_path;
+
+    #[test]
+    fn one_arg_ok() {
+        assert_eq!(
+            parse_file_path(&["script_path".to_string(), "arg_text".to_string()]),
+            ("arg_text", false)
+        );
+    }
+
+    #[test]
+    fn stdin_arg_ok() {
+        assert_eq!(
+            parse_file_path(&["script_path".to_string(), "-".to_string()]),
+            ("-", false)
+        );
+    }
+
+    #[test]
+    fn animate_arg_ok() {
+        assert_eq!(
+            parse_file_path(&[
+                "script_path".to_string(),
+                "arg_text".to_string(),
+                "--animate".to_string()
+            ]),
+            ("arg_text", true)
+        );
+    }
 
     #[test]
-    fn example_correct() {
-        assert_eq!(solution("inputs/example.txt", 100), (1656, 195));
+    #[should_panic]
+    fn unknown_arg_fail() {
+        parse_file_path(&[
+            "script_path".to_string(),
+            "arg_text".to_string(),
+            "extra_arg".to_string(),
+        ]);
     }
 
     #[test]
-    fn question_correct() {
-        assert_eq!(solution("inputs/challenge.txt", 100), (1613, 510));
+    #[should_panic]
+    fn no_arg_fail() {
+        parse_file_path(&Vec::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn many_arg_fail() {
+        parse_file_path(&[
+            "script_path".to_string(),
+            "arg_text".to_string(),
+            "--animate".to_string(),
+            "extra_arg".to_string(),
+        ]);
     }
 }
@@ -0,0 +1,69 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day_12::solution;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::hint::black_box;
+
+/// Count all paths through a cave system the way the solver did before tracking visited caves as a bitmask.
+///
+/// Every branch of the DFS clones the path taken so far, and checks whether a small cave was visited by scanning it.
+fn cloned_paths(input_path: &str) -> usize {
+    let contents = fs::read_to_string(input_path).expect("Error reading file");
+    let mut adjascency: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (source, target) in contents.lines().filter_map(|line| line.split_once('-')) {
+        // Links are bidirectional for all caves but start->cave and cave->end
+        if target != "start" && source != "end" {
+            adjascency.entry(source).or_default().push(target);
+        }
+        if source != "start" && target != "end" {
+            adjascency.entry(target).or_default().push(source);
+        }
+    }
+    let is_large = |cave: &str| cave == cave.to_uppercase();
+
+    let mut paths_to_end = 0;
+    let mut to_search = VecDeque::new();
+    to_search.push_back(("start", Vec::new(), false));
+    while let Some((cave, mut path, has_double_small)) = to_search.pop_front() {
+        path.push(cave);
+        if cave == "end" {
+            paths_to_end += 1;
+            continue;
+        }
+        for neighbor in adjascency.get(cave).into_iter().flatten() {
+            let has_this_small_neighbor = !is_large(neighbor) && path.contains(neighbor);
+            if has_double_small && has_this_small_neighbor {
+                continue;
+            }
+            to_search.push_front((
+                neighbor,
+                path.clone(),
+                has_double_small || has_this_small_neighbor,
+            ));
+        }
+    }
+    paths_to_end
+}
+
+/// Benchmark counting all paths through the challenge cave system, against the cloned path traversal it replaced.
+fn bench_solution(c: &mut Criterion) {
+    let input_path = "inputs/challenge.txt";
+    assert_eq!(
+        cloned_paths(input_path),
+        solution(input_path, &[], None).unwrap()
+    );
+
+    let mut group = c.benchmark_group("day-12");
+    group.throughput(Throughput::Elements(1));
+    group.sample_size(10);
+    group.bench_function("challenge", |b| {
+        b.iter(|| solution(black_box(input_path), &[], None).unwrap())
+    });
+    group.bench_function("challenge cloned paths", |b| {
+        b.iter(|| cloned_paths(black_box(input_path)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_solution);
+criterion_main!(benches);
@@ -0,0 +1,532 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Errors encountered while building a graph.
+#[derive(Debug, PartialEq)]
+pub enum GraphError {
+    /// The graph had no `start` node.
+    MissingStart,
+    /// The graph had no `end` node.
+    MissingEnd,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::MissingStart => write!(f, "Graph has no start node"),
+            GraphError::MissingEnd => write!(f, "Graph has no end node"),
+        }
+    }
+}
+
+impl Error for GraphError {}
+
+struct Node {
+    id: usize,
+    name: String,
+    is_start: bool,
+    is_end: bool,
+    is_large: bool,
+}
+
+struct Graph {
+    nodes: Vec<Node>,
+    adjascency: HashMap<usize, Vec<usize>>,
+    starting_node_idx: usize,
+}
+
+impl Graph {
+    /// Parse a single {source_node}-{target_node} connection, returning None unless the line has exactly two
+    /// non-empty node names.
+    fn parse_edge(line: &str) -> Option<(String, String)> {
+        let (source, target) = line.split_once('-')?;
+        if source.is_empty() || target.is_empty() || target.contains('-') {
+            return None;
+        }
+        Some((source.to_string(), target.to_string()))
+    }
+
+    /// Create a Graph using an iterator of lines containing graph connections.
+    ///
+    /// Connections can be of the form {source_node}-{target_node}, where
+    /// `start` is the starting node and `end` is the final node.
+    /// All edges are bidirectional. Lowercase nodes are taken to be "small" - e.g. can only be visited
+    /// once in a traversal. Otherwise, nodes are taken to be "large" and can be visited multiple times
+    /// in a traversal. A single small node in a traversal may be visited twice, but all others must be visited
+    /// only once. Malformed lines that are not exactly two non-empty node names are skipped with a warning, and
+    /// self-edges (e.g. `b-b`) are ignored. Returns an error if the graph has no `start` or `end` node.
+    fn from_lines<I>(lines: I) -> Result<Graph, GraphError>
+    where
+        I: Iterator<Item = Result<String, std::io::Error>>,
+    {
+        // Parse out all the node names and their mappings, two names (source then target) per edge
+        let node_names = lines
+            .map(|line| line.expect("Failed to read line from file."))
+            .filter_map(|line| match Graph::parse_edge(&line) {
+                Some(edge) => Some(edge),
+                None => {
+                    eprintln!("Skipping malformed edge: {:?}", line);
+                    None
+                }
+            })
+            // A self-edge can never be part of a valid path (a small node can't follow itself, and a large node
+            // revisiting itself would loop forever), so drop them before building the adjacency list
+            .filter(|(source, target)| source != target)
+            .flat_map(|(source, target)| [source, target])
+            .collect::<Vec<String>>();
+
+        // Assign each node name a unique ID
+        let mut current_node_id = 0;
+        let mut name_to_id = HashMap::new();
+        for node_name in &node_names {
+            if !name_to_id.contains_key(node_name) {
+                name_to_id.insert(node_name, current_node_id);
+                current_node_id += 1;
+            }
+        }
+
+        // Visited nodes are tracked as bits of a u64 during traversal
+        if name_to_id.len() > u64::BITS as usize {
+            panic!(
+                "Graphs can have at most {} nodes, got: {}",
+                u64::BITS,
+                name_to_id.len()
+            );
+        }
+
+        // Create a vector of unique nodes
+        let mut nodes = Vec::new();
+        // Fill nodes with garbage to insert real nodes after
+        for _ in 0..name_to_id.len() {
+            nodes.push(Node {
+                id: 0,
+                name: "".to_string(),
+                is_start: true,
+                is_end: true,
+                is_large: true,
+            });
+        }
+        let mut starting_node_idx = None;
+        let mut ending_node_idx = None;
+        for (node_name, node_id) in &name_to_id {
+            let node_name = *node_name;
+            let node = Node {
+                id: *node_id,
+                name: node_name.clone(),
+                is_start: node_name == "start",
+                is_end: node_name == "end",
+                is_large: node_name == &node_name.to_uppercase(),
+            };
+            if node.is_start {
+                starting_node_idx = Some(node.id);
+            }
+            if node.is_end {
+                ending_node_idx = Some(node.id);
+            }
+            nodes[*node_id] = node;
+        }
+        let starting_node_idx = starting_node_idx.ok_or(GraphError::MissingStart)?;
+        let ending_node_idx = ending_node_idx.ok_or(GraphError::MissingEnd)?;
+        let mut adj = HashMap::new();
+        for idx in (0..node_names.len()).step_by(2) {
+            let mut id0 = name_to_id.get(&node_names[idx]).unwrap();
+            let mut id1 = name_to_id.get(&node_names[idx + 1]).unwrap();
+            // Always want start -> node relationships, not node -> start relationships
+            if *id1 == starting_node_idx {
+                std::mem::swap(&mut id0, &mut id1);
+            }
+            let _ = adj
+                .entry(*id0)
+                .and_modify(|v: &mut Vec<usize>| v.push(*id1))
+                .or_insert(vec![*id1]);
+            // links are bidirectional for all nodes but start->node and node->end
+            if *id0 == starting_node_idx || *id1 == ending_node_idx {
+                continue;
+            }
+            let _ = adj
+                .entry(*id1)
+                .and_modify(|v: &mut Vec<usize>| v.push(*id0))
+                .or_insert(vec![*id0]);
+        }
+        Ok(Graph {
+            nodes,
+            adjascency: adj,
+            starting_node_idx,
+        })
+    }
+
+    /// Find all adjascent nodes to the given node index in the graph.
+    ///
+    /// Uses a pre-calculated adjascency list, so lookups are O(1).
+    fn neighbors(&self, idx: usize) -> Vec<&Node> {
+        let mut neighbor_nodes = Vec::new();
+        if let Some(node_ids) = self.adjascency.get(&idx) {
+            for id in node_ids {
+                neighbor_nodes.push(self.get(*id));
+            }
+        }
+        neighbor_nodes
+    }
+
+    /// Render the graph in Graphviz DOT format, drawing large nodes as boxes and small nodes as ellipses.
+    ///
+    /// Each connection is emitted once as an undirected `source -- target` edge, in the order nodes were first seen.
+    fn to_dot(&self) -> String {
+        let mut dot = "graph {\n".to_string();
+        for node in &self.nodes {
+            let shape = if node.is_large { "box" } else { "ellipse" };
+            dot += &format!("    {} [shape={}];\n", node.name, shape);
+        }
+        let mut seen = HashSet::new();
+        for node in &self.nodes {
+            for neighbor in self.neighbors(node.id) {
+                let edge = (node.id.min(neighbor.id), node.id.max(neighbor.id));
+                if seen.insert(edge) {
+                    dot += &format!("    {} -- {};\n", node.name, neighbor.name);
+                }
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+
+    /// Get a node at a given index.
+    fn get(&self, idx: usize) -> &Node {
+        &self.nodes[idx]
+    }
+
+    /// Count the number of valid traversals from the starting node to the ending node.
+    ///
+    /// Uses DFS to traverse all paths in the graph, tracking the small nodes visited on each path as a bitmask of
+    /// node ids. Nodes named in `revisitable` are treated as large regardless of case - note that two connected
+    /// large nodes can be visited forever, so the traversal won't finish unless `max_depth` is set. Only paths of at
+    /// most `max_depth` edges are counted, if given.
+    fn get_paths_to_end_dfs(&self, revisitable: &[&str], max_depth: Option<usize>) -> usize {
+        let revisitable = self
+            .nodes
+            .iter()
+            .filter(|node| revisitable.contains(&node.name.as_str()))
+            .fold(0u64, |mask, node| mask | 1 << node.id);
+        let is_large = |node: &Node| node.is_large || revisitable & (1 << node.id) != 0;
+
+        let mut nodes_to_search = VecDeque::new();
+        let mut paths_to_end = 0;
+        nodes_to_search.push_back((self.get(self.starting_node_idx), 0u64, false, 0));
+
+        while let Some((this_node, mut visited_small, has_double_small, depth)) =
+            nodes_to_search.pop_front()
+        {
+            if !is_large(this_node) {
+                visited_small |= 1 << this_node.id;
+            }
+            if this_node.is_end {
+                paths_to_end += 1;
+                continue;
+            }
+            // Any path continuing from here would be too long
+            if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                continue;
+            }
+
+            for neighbor in self.neighbors(this_node.id) {
+                let has_this_small_neighbor =
+                    (!is_large(neighbor)) && visited_small & (1 << neighbor.id) != 0;
+                if has_double_small && has_this_small_neighbor {
+                    continue;
+                }
+                nodes_to_search.push_front((
+                    neighbor,
+                    visited_small,
+                    has_double_small || has_this_small_neighbor,
+                    depth + 1,
+                ));
+            }
+        }
+        paths_to_end
+    }
+}
+
+#[cfg(test)]
+mod test_graph {
+    use crate::Graph;
+
+    fn lines(edges: &[&str]) -> impl Iterator<Item = Result<String, std::io::Error>> {
+        edges
+            .iter()
+            .map(|edge| Ok(edge.to_string()))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn malformed_edges_skipped() {
+        let valid = Graph::from_lines(lines(&["start-A", "A-b", "b-end", "A-end"])).unwrap();
+        let with_garbage = Graph::from_lines(lines(&[
+            "start-A", "garbage", "A-b", "A-b-c", "-b", "b-end", "A-", "A-end",
+        ]))
+        .unwrap();
+        assert_eq!(valid.get_paths_to_end_dfs(&[], None), 5);
+        assert_eq!(with_garbage.get_paths_to_end_dfs(&[], None), 5);
+        assert_eq!(with_garbage.nodes.len(), valid.nodes.len());
+    }
+
+    #[test]
+    fn max_nodes_ok() {
+        // start, end, and 62 caves between them
+        let edges = (0..62)
+            .flat_map(|idx| [format!("start-n{}", idx), format!("n{}-end", idx)])
+            .collect::<Vec<String>>();
+        let edges = edges.iter().map(|e| e.as_str()).collect::<Vec<&str>>();
+        let graph = Graph::from_lines(lines(&edges)).unwrap();
+        assert_eq!(graph.nodes.len(), 64);
+        assert_eq!(graph.get_paths_to_end_dfs(&[], None), 62);
+    }
+
+    #[test]
+    #[should_panic(expected = "Graphs can have at most 64 nodes, got: 65")]
+    fn too_many_nodes_fail() {
+        let edges = (0..64)
+            .map(|idx| format!("start-n{}", idx))
+            .collect::<Vec<String>>();
+        let edges = edges.iter().map(|e| e.as_str()).collect::<Vec<&str>>();
+        let _ = Graph::from_lines(lines(&edges));
+    }
+
+    #[test]
+    fn self_edges_ignored() {
+        let edges = ["start-A", "A-b", "b-end", "A-end"];
+        let with_loops = ["start-A", "A-b", "b-b", "A-A", "b-end", "A-end"];
+        assert_eq!(
+            Graph::from_lines(lines(&with_loops))
+                .unwrap()
+                .get_paths_to_end_dfs(&[], None),
+            Graph::from_lines(lines(&edges))
+                .unwrap()
+                .get_paths_to_end_dfs(&[], None)
+        );
+    }
+
+    #[test]
+    fn revisitable_small_node_adds_paths() {
+        let edges = ["start-A", "A-c", "c-b", "b-d", "d-end", "A-end"];
+        let graph = Graph::from_lines(lines(&edges)).unwrap();
+        assert_eq!(graph.get_paths_to_end_dfs(&[], None), 6);
+        assert_eq!(graph.get_paths_to_end_dfs(&["b"], None), 9);
+    }
+
+    #[test]
+    fn duplicate_edges_drawn_once() {
+        let graph = Graph::from_lines(lines(&["start-A", "A-end", "end-A"])).unwrap();
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n    start [shape=ellipse];\n    A [shape=box];\n    end [shape=ellipse];\n    start -- A;\n    A -- end;\n}\n"
+        );
+    }
+}
+
+/// Count the number of viable paths from the starting node to the ending node in a graph.
+///
+/// There are two types of graph nodes:
+///
+/// * Large nodes - can be visited any number of times in a traversal, denoted by an uppercase node name
+/// * Small nodes - can be visited only once in a traversal, denoted by a lowercase node name
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the graph to traverse.
+/// * `revisitable` - Names of small nodes to treat as large, so they can be visited any number of times.
+/// * `max_depth` - The most edges a path can follow, or None for no limit - connected large nodes have infinitely many
+///   paths between them, so only finish counting when a limit is given.
+///
+/// # Returns
+///
+/// The number of distinct paths from start to end of at most `max_depth` edges, or an error if the graph has no
+/// start or end node.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The following example has 10 paths:
+///
+/// ```text
+/// start-A
+/// start-b
+/// A-c
+/// A-b
+/// b-d
+/// A-end
+/// b-end
+/// ```
+///
+/// ```text
+///    start
+///     /   \
+/// c--A-----b--d
+///     \   /
+///      end
+/// ```
+pub fn solution(
+    input_path: &str,
+    revisitable: &[&str],
+    max_depth: Option<usize>,
+) -> Result<usize, GraphError> {
+    let reader = get_buf_reader(input_path);
+    Ok(Graph::from_lines(reader.lines())?.get_paths_to_end_dfs(revisitable, max_depth))
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, GraphError};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution("inputs/example.txt", &[], None), Ok(36));
+    }
+
+    #[test]
+    fn example_medium_correct() {
+        assert_eq!(solution("inputs/example_medium.txt", &[], None), Ok(103));
+    }
+    #[test]
+    fn example_large_correct() {
+        assert_eq!(solution("inputs/example_large.txt", &[], None), Ok(3509));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt", &[], None), Ok(96988));
+    }
+
+    #[test]
+    fn example_bounded_correct() {
+        // The longest example paths visit b twice, e.g. start,A,b,A,c,A,b,end
+        assert_eq!(solution("inputs/example.txt", &[], Some(100)), Ok(36));
+        assert_eq!(solution("inputs/example.txt", &[], Some(2)), Ok(2));
+    }
+
+    #[test]
+    fn large_cycle_bounded() {
+        // A and B can be bounced between forever, each bounce adding two edges to start,A,B,end
+        assert_eq!(solution("inputs/example_cycle.txt", &[], Some(3)), Ok(1));
+        assert_eq!(solution("inputs/example_cycle.txt", &[], Some(6)), Ok(2));
+        assert_eq!(solution("inputs/example_cycle.txt", &[], Some(7)), Ok(3));
+    }
+
+    #[test]
+    fn missing_end_fail() {
+        assert_eq!(
+            solution("inputs/example_no_end.txt", &[], None),
+            Err(GraphError::MissingEnd)
+        );
+    }
+
+    #[test]
+    fn empty_input_fail() {
+        assert_eq!(
+            solution("inputs/example_empty.txt", &[], None),
+            Err(GraphError::MissingStart)
+        );
+    }
+}
+
+/// Render a graph in Graphviz DOT format, for visualizing the cave system.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the graph to render.
+///
+/// # Returns
+///
+/// The graph as DOT, with large nodes drawn as boxes, or an error if the graph has no start or end node.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The example graph from `solution` renders as:
+///
+/// ```text
+/// graph {
+///     start [shape=ellipse];
+///     A [shape=box];
+///     ...
+///     start -- A;
+///     start -- b;
+///     ...
+/// }
+/// ```
+pub fn dot(input_path: &str) -> Result<String, GraphError> {
+    let reader = get_buf_reader(input_path);
+    Ok(Graph::from_lines(reader.lines())?.to_dot())
+}
+
+#[cfg(test)]
+mod test_dot {
+    use crate::{dot, GraphError};
+
+    #[test]
+    fn example_correct() {
+        let dot = dot("inputs/example.txt").unwrap();
+        assert!(dot.starts_with("graph {\n"));
+        assert!(dot.contains("    A [shape=box];\n"));
+        assert!(dot.contains("    c [shape=ellipse];\n"));
+        let edges = dot.lines().filter(|line| line.contains(" -- ")).count();
+        assert_eq!(edges, 7);
+        for edge in [
+            "start -- A;",
+            "start -- b;",
+            "A -- c;",
+            "A -- b;",
+            "b -- d;",
+            "A -- end;",
+            "b -- end;",
+        ] {
+            assert!(dot.contains(edge), "Missing edge {} in {}", edge, dot);
+        }
+    }
+
+    #[test]
+    fn missing_end_fail() {
+        assert_eq!(
+            dot("inputs/example_no_end.txt"),
+            Err(GraphError::MissingEnd)
+        );
+    }
+}
@@ -0,0 +1,582 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// A fold instruction, where the crease is `at` lines from the far (right or bottom) edge if `from_far_edge` is set,
+/// rather than `at` lines from the origin.
+struct Fold {
+    axis: String,
+    at: usize,
+    from_far_edge: bool,
+}
+
+/// Errors encountered while following fold instructions.
+#[derive(Debug, PartialEq)]
+pub enum FoldError {
+    /// A fold instruction named an axis other than `x` or `y`.
+    InvalidAxis(String),
+    /// A fold instruction placed its crease outside of the visible paper.
+    OutOfRange { axis: String, at: usize, dim: usize },
+    /// A fold instruction placed its crease further from the far edge than the visible paper.
+    OffsetOutOfRange {
+        axis: String,
+        offset: usize,
+        dim: usize,
+    },
+}
+
+impl fmt::Display for FoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FoldError::InvalidAxis(axis) => write!(f, "Invalid fold axis: {:?}", axis),
+            FoldError::OutOfRange { axis, at, dim } => write!(
+                f,
+                "Fold along {}={} is outside of the paper, which is {} wide on that axis",
+                axis, at, dim
+            ),
+            FoldError::OffsetOutOfRange { axis, offset, dim } => write!(
+                f,
+                "Fold along {}=-{} is outside of the paper, which is {} wide on that axis",
+                axis, offset, dim
+            ),
+        }
+    }
+}
+
+impl Error for FoldError {}
+
+/// A fixed number of bits, packed 64 to a word so that large sheets take an eighth of the memory of a `Vec<bool>`.
+struct Bits {
+    words: Vec<u64>,
+}
+
+impl Bits {
+    /// Create `len` bits, all unset.
+    fn new(len: usize) -> Bits {
+        Bits {
+            words: vec![0; len.div_ceil(u64::BITS as usize)],
+        }
+    }
+
+    /// Return True if the bit at the given index is set.
+    fn get(&self, idx: usize) -> bool {
+        let (word, bit) = (idx / u64::BITS as usize, idx % u64::BITS as usize);
+        self.words[word] & (1 << bit) != 0
+    }
+
+    /// Set or unset the bit at the given index.
+    fn set(&mut self, idx: usize, value: bool) {
+        let (word, bit) = (idx / u64::BITS as usize, idx % u64::BITS as usize);
+        if value {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+    }
+
+    /// Return the count of set bits.
+    fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test_bits {
+    use crate::Bits;
+
+    #[test]
+    fn set_across_words() {
+        let mut bits = Bits::new(130);
+        for idx in [0, 63, 64, 129] {
+            bits.set(idx, true);
+        }
+        assert_eq!(bits.count_ones(), 4);
+        assert!(bits.get(63) && bits.get(64) && bits.get(129));
+        assert!(!bits.get(1) && !bits.get(65) && !bits.get(128));
+        bits.set(64, false);
+        assert!(!bits.get(64));
+        assert_eq!(bits.count_ones(), 3);
+    }
+}
+
+struct DotMatrix {
+    matrix: Bits,
+    x_dim: usize,
+    x_view_dim: usize,
+    y_view_dim: usize,
+}
+
+/// Map a 2d matrix onto a 1d array
+/// where each array segment of x elements represents one part of the y axis:
+/// [(0,0), (1,0), (2,0), ..., (x_dim,0),
+///  ...,
+///  (0,y_dim), (1,y_dim), (2,y_dim), ..., (x_dim,y_dim)]
+/// Therefore there are x "columns" representing the x axis, and
+/// there are y "rows" representing the y axis.
+impl DotMatrix {
+    // Create a DotMatrix using a vector of x, y coordinates indicating the "points" that are turned on.
+    //
+    // The canvas size is inferred from the largest coordinates unless an explicit (width, height) is given, which
+    // must be large enough to hold every point.
+    fn from_points(points: Vec<(usize, usize)>, canvas: Option<(usize, usize)>) -> DotMatrix {
+        let x_dim = points.iter().map(|t| t.0).max().unwrap() + 1;
+        let y_dim = points.iter().map(|t| t.1).max().unwrap() + 1;
+        let (x_dim, y_dim) = match canvas {
+            Some((width, height)) if width < x_dim || height < y_dim => panic!(
+                "Canvas of {}x{} is too small for points up to {}x{}",
+                width, height, x_dim, y_dim
+            ),
+            Some(canvas) => canvas,
+            None => (x_dim, y_dim),
+        };
+        let mut matrix = Bits::new(x_dim * y_dim);
+        for (x, y) in points {
+            matrix.set(x + (y * x_dim), true);
+        }
+        DotMatrix {
+            matrix,
+            x_dim,
+            x_view_dim: x_dim,
+            y_view_dim: y_dim,
+        }
+    }
+
+    /// Print a representation of the DotMatrix.
+    fn print(&self) {
+        print!("{}", self.render());
+    }
+
+    /// Render the visible part of the DotMatrix, one row per line, with `#` for active points and `.` otherwise.
+    fn render(&self) -> String {
+        let mut rendered = String::new();
+        for y in 0..self.y_view_dim {
+            for x in 0..self.x_view_dim {
+                rendered.push(if self.matrix.get(x + (y * self.x_dim)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    /// Reflect a coordinate on an axis `dim` long about a crease at `at`, returning its position on the folded axis.
+    ///
+    /// The folded axis is as long as the longer side of the crease, so when the far side is longer it extends past the
+    /// origin and the near side shifts along to meet it. Coordinates on the crease itself are dropped.
+    fn _fold_coord(coord: usize, at: usize, dim: usize) -> Option<usize> {
+        let folded_dim = at.max(dim - 1 - at);
+        match coord.cmp(&at) {
+            Ordering::Less => Some(coord + folded_dim - at),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(folded_dim + at - coord),
+        }
+    }
+
+    /// Fold every visible point across a crease, where `fold_point` maps an (x, y) point to its folded position.
+    fn _fold_points<F>(&mut self, fold_point: F)
+    where
+        F: Fn(usize, usize) -> Option<(usize, usize)>,
+    {
+        let mut folded = Vec::new();
+        for y in 0..self.y_view_dim {
+            for x in 0..self.x_view_dim {
+                if self.matrix.get(x + (y * self.x_dim)) {
+                    // Unset the original point since it's being moved over
+                    self.matrix.set(x + (y * self.x_dim), false);
+                    folded.extend(fold_point(x, y));
+                }
+            }
+        }
+        for (x, y) in folded {
+            self.matrix.set(x + (y * self.x_dim), true);
+        }
+    }
+
+    /// Fold the left and right sides of the paper together along x = `at`, returning the new width.
+    fn _fold_x(&mut self, at: usize) -> usize {
+        let dim = self.x_view_dim;
+        self._fold_points(|x, y| DotMatrix::_fold_coord(x, at, dim).map(|x| (x, y)));
+        at.max(dim - 1 - at)
+    }
+
+    /// Fold the top and bottom sides of the paper together along y = `at`, returning the new height.
+    fn _fold_y(&mut self, at: usize) -> usize {
+        let dim = self.y_view_dim;
+        self._fold_points(|x, y| DotMatrix::_fold_coord(y, at, dim).map(|y| (x, y)));
+        at.max(dim - 1 - at)
+    }
+
+    /// Fold the matrix along an axis at a given boundary.
+    ///
+    /// Creases measured from the far edge are converted to an absolute crease against the current visible paper, so
+    /// `x=-3` on paper 11 wide folds at `x=7`.
+    fn fold(&mut self, fold: &Fold) -> Result<(), FoldError> {
+        let dim = match fold.axis.as_str() {
+            "x" => self.x_view_dim,
+            "y" => self.y_view_dim,
+            _ => return Err(FoldError::InvalidAxis(fold.axis.clone())),
+        };
+        let at = if fold.from_far_edge {
+            if fold.at >= dim {
+                return Err(FoldError::OffsetOutOfRange {
+                    axis: fold.axis.clone(),
+                    offset: fold.at,
+                    dim,
+                });
+            }
+            dim - 1 - fold.at
+        } else {
+            fold.at
+        };
+        if at >= dim {
+            return Err(FoldError::OutOfRange {
+                axis: fold.axis.clone(),
+                at,
+                dim,
+            });
+        }
+        // Change the basis for future printing & folding
+        if fold.axis == "x" {
+            self.x_view_dim = self._fold_x(at);
+        } else {
+            self.y_view_dim = self._fold_y(at);
+        }
+        Ok(())
+    }
+
+    /// Return the count of active points in the matrix.
+    fn active_count(&self) -> usize {
+        self.matrix.count_ones()
+    }
+}
+
+#[cfg(test)]
+mod test_dot_matrix {
+    use crate::{parse_input, DotMatrix, Fold};
+
+    #[test]
+    fn example_folds_correct() {
+        let (points, folds) = parse_input("inputs/example.txt");
+        let mut m = DotMatrix::from_points(points, None);
+        m.fold(&folds[0]).unwrap();
+        assert_eq!(m.active_count(), 17);
+        assert_eq!(
+            m.render(),
+            "#.##..#..#.\n#...#......\n......#...#\n#...#......\n.#.#..#.###\n...........\n...........\n"
+        );
+        m.fold(&folds[1]).unwrap();
+        assert_eq!(m.active_count(), 16);
+        assert_eq!(
+            m.render(),
+            "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n"
+        );
+    }
+
+    #[test]
+    fn large_sheet_packed() {
+        // A 1000x1000 sheet takes 1,000,000 bits, packed into 15,625 words
+        let mut m = DotMatrix::from_points(vec![(0, 0), (999, 999)], None);
+        assert_eq!(m.matrix.words.len(), 15_625);
+        m.fold(&Fold {
+            axis: "x".to_string(),
+            at: 499,
+            from_far_edge: false,
+        })
+        .unwrap();
+        assert_eq!(m.active_count(), 2);
+    }
+
+    #[test]
+    fn off_center_far_edge_fold() {
+        // x=-3 on paper 11 wide creases at x=7, so x=10 lands on x=4 and x=8 on x=6
+        let mut m = DotMatrix::from_points(vec![(0, 0), (10, 0), (8, 1)], None);
+        m.fold(&Fold {
+            axis: "x".to_string(),
+            at: 3,
+            from_far_edge: true,
+        })
+        .unwrap();
+        assert_eq!(m.render(), "#...#..\n......#\n");
+    }
+
+    #[test]
+    fn off_center_fold_past_origin() {
+        // x=2 on paper 11 wide leaves 8 columns past the crease, so the left side shifts along to meet them
+        let mut m = DotMatrix::from_points(vec![(0, 0), (10, 0), (8, 1), (2, 1)], None);
+        m.fold(&Fold {
+            axis: "x".to_string(),
+            at: 2,
+            from_far_edge: false,
+        })
+        .unwrap();
+        assert_eq!(m.render(), "#.....#.\n..#.....\n");
+        // The point on the crease is dropped
+        assert_eq!(m.active_count(), 3);
+    }
+}
+
+/// Parse the points and fold instructions out of an input file.
+fn parse_input(input_path: &str) -> (Vec<(usize, usize)>, Vec<Fold>) {
+    let reader = get_buf_reader(input_path);
+
+    let mut points = Vec::new();
+    let mut folds = Vec::new();
+
+    for line in reader.lines() {
+        let line = line
+            .expect("Failed to read line from file.")
+            .trim()
+            .replace("fold along ", "");
+        if let Some((left, right)) = line.split_once(',') {
+            points.push((
+                left.parse::<usize>().unwrap(),
+                right.parse::<usize>().unwrap(),
+            ));
+        }
+        if let Some((left, right)) = line.split_once('=') {
+            // A leading `-` measures the crease from the far edge rather than the origin
+            let (right, from_far_edge) = match right.strip_prefix('-') {
+                Some(offset) => (offset, true),
+                None => (right, false),
+            };
+            folds.push(Fold {
+                axis: left.to_string(),
+                at: right.parse::<usize>().unwrap(),
+                from_far_edge,
+            });
+        }
+    }
+    (points, folds)
+}
+
+/// Parse a set of points from an input, and follow a set of "fold" instructions to transform the points.
+///
+/// For example, the input:
+///
+/// ```text
+/// 6,10
+/// 0,14
+/// 9,10
+/// 0,3
+/// 10,4
+/// 4,11
+/// 6,0
+/// 6,12
+/// 4,1
+/// 0,13
+/// 10,12
+/// 3,4
+/// 3,0
+/// 8,4
+/// 1,10
+/// 2,14
+/// 8,10
+/// 9,0
+///
+/// fold along y=7
+/// fold along x=5
+/// ```
+///
+/// Indicates mapping all points y > 7 down with a vertical reflection, followed by all points with
+/// x > 5 left with a horizontal reflection.
+///
+/// A crease with a leading `-` is measured from the far edge of the visible paper instead, so on the 15 line tall
+/// example `fold along y=-7` is the same as `fold along y=7`.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path contianing the points and fold instructions.
+/// * `num_folds` - The number of fold instructions to perform.
+/// * `canvas` - The (width, height) of the paper, inferred from the largest point coordinates if None.
+///
+/// # Returns
+///
+/// The number of dots visible after N folds, or an error if a fold instruction is invalid.
+pub fn solution(
+    input_path: &str,
+    num_folds: usize,
+    canvas: Option<(usize, usize)>,
+) -> Result<usize, FoldError> {
+    let (points, folds) = parse_input(input_path);
+
+    // Avoid passing num_folds more than specified in the input file
+    let num_folds = if num_folds > folds.len() || num_folds == 0 {
+        folds.len()
+    } else {
+        num_folds
+    };
+
+    let mut m = DotMatrix::from_points(points, canvas);
+    for fold in folds.iter().take(num_folds) {
+        let sign = if fold.from_far_edge { "-" } else { "" };
+        println!("Performing {}={}{} fold", fold.axis, sign, fold.at);
+        m.fold(fold)?;
+    }
+
+    if m.x_view_dim < 100 && m.y_view_dim < 100 {
+        println!("Folded matrix:");
+        m.print();
+    }
+    Ok(m.active_count())
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{fold_counts, solution, FoldError};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution("inputs/example.txt", 1, None), Ok(17));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt", 1, None), Ok(720));
+    }
+
+    #[test]
+    fn question_part2_correct() {
+        assert_eq!(solution("inputs/challenge.txt", 0, None), Ok(104));
+    }
+
+    #[test]
+    fn explicit_canvas_correct() {
+        // Folding at x=5 maps 6,0 onto 4,0 whether the paper is 11 wide or an inferred 7 wide, since points are
+        // reflected about the crease rather than the middle of the paper
+        assert_eq!(
+            solution("inputs/example_canvas.txt", 1, Some((11, 1))),
+            Ok(2)
+        );
+        assert_eq!(solution("inputs/example_canvas.txt", 1, None), Ok(2));
+    }
+
+    #[test]
+    fn invalid_axis_fail() {
+        assert_eq!(
+            solution("inputs/example_bad_axis.txt", 1, None),
+            Err(FoldError::InvalidAxis("z".to_string()))
+        );
+    }
+
+    #[test]
+    fn out_of_range_crease_fail() {
+        assert_eq!(
+            solution("inputs/example_bad_crease.txt", 1, None),
+            Err(FoldError::OutOfRange {
+                axis: "x".to_string(),
+                at: 9,
+                dim: 7
+            })
+        );
+    }
+
+    #[test]
+    fn relative_crease_matches_absolute() {
+        assert_eq!(
+            fold_counts("inputs/example_relative.txt", None),
+            fold_counts("inputs/example.txt", None)
+        );
+    }
+
+    #[test]
+    fn out_of_range_offset_fail() {
+        assert_eq!(
+            solution("inputs/example_bad_offset.txt", 1, None),
+            Err(FoldError::OffsetOutOfRange {
+                axis: "x".to_string(),
+                offset: 7,
+                dim: 7
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn small_canvas_fail() {
+        let _ = solution("inputs/example.txt", 1, Some((5, 5)));
+    }
+}
+
+/// Follow every fold instruction in an input, counting the visible dots after each fold.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path contianing the points and fold instructions.
+/// * `canvas` - The (width, height) of the paper, inferred from the largest point coordinates if None.
+///
+/// # Returns
+///
+/// The number of dots visible after each fold, in the order the folds are performed, or an error if a fold
+/// instruction is invalid.
+pub fn fold_counts(
+    input_path: &str,
+    canvas: Option<(usize, usize)>,
+) -> Result<Vec<usize>, FoldError> {
+    let (points, folds) = parse_input(input_path);
+    let mut m = DotMatrix::from_points(points, canvas);
+    folds
+        .iter()
+        .map(|fold| {
+            m.fold(fold)?;
+            Ok(m.active_count())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_fold_counts {
+    use crate::fold_counts;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(fold_counts("inputs/example.txt", None), Ok(vec![17, 16]));
+    }
+
+    #[test]
+    fn question_correct() {
+        let counts = fold_counts("inputs/challenge.txt", None).unwrap();
+        assert_eq!(counts.first(), Some(&720));
+        assert_eq!(counts.last(), Some(&104));
+    }
+}
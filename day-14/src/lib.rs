@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Error};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Parse a polymer creation template and return the final polymer chain after N steps.
+///
+/// Templates have the form:
+///
+/// ```text
+/// NNCB
+///
+/// CH -> B
+/// HH -> N
+/// CB -> H
+/// NH -> C
+/// HB -> C
+/// HC -> B
+/// HN -> C
+/// NN -> C
+/// BH -> H
+/// NC -> B
+/// NB -> B
+/// BN -> B
+/// BB -> N
+/// BC -> B
+/// CC -> N
+/// CN -> C
+/// ```
+///
+/// Where the first line `NNCB` is the polymer template,
+/// and the subsequent lines are insertion rules indicating that pairs
+/// of letters should have new characters inserted between them (eg. `CH` becomes `CBH`).
+///
+/// These rules can be applied multiple times to the starting string to create a final
+/// polymer chain.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the polymer rules.
+/// * `num_steps` - The number of times to apply insertion rules
+///
+/// # Returns
+///
+/// The quantity of the most common element minus the quantity of the least common element after N steps.
+pub fn solution(input_path: &str, num_steps: usize) -> usize {
+    let reader = get_buf_reader(input_path);
+    let mut lines = reader.lines();
+    let template = lines
+        .next()
+        .expect("Empty file found.")
+        .expect("Empty file found.");
+    apply_rules(&template, lines, num_steps)
+}
+
+/// Apply lines of insertion rules to a polymer template N times, returning the quantity of the most common element
+/// minus the quantity of the least common element.
+fn apply_rules<I>(template: &str, rule_lines: I, num_steps: usize) -> usize
+where
+    I: Iterator<Item = Result<String, Error>>,
+{
+    let element_counts = element_counts(template, rule_lines, num_steps);
+    let difference = element_counts.iter().max().unwrap() - element_counts.iter().min().unwrap();
+    usize::try_from(difference).expect("Element count difference overflows usize")
+}
+
+/// Apply lines of insertion rules to a polymer template N times, returning the quantity of each element in the
+/// final polymer.
+///
+/// Counts are kept as u128, since the polymer roughly doubles in length with every step.
+fn element_counts<I>(template: &str, rule_lines: I, num_steps: usize) -> Vec<u128>
+where
+    I: Iterator<Item = Result<String, Error>>,
+{
+    // Parse the polymer starting string into a list of single characters
+    let polymer = template
+        .split("")
+        .filter(|s| s != &"")
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+
+    // Count all pairs in the current string
+    // This is where we will store the running totals of character occurrences,
+    // as well as occurences of pairs of characters
+    let mut pair_counts: HashMap<String, u128> = HashMap::new();
+    for idx in 0..polymer.len() - 1 {
+        let match_pair = polymer[idx].clone() + &polymer[idx + 1];
+        pair_counts
+            .entry(match_pair)
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+        pair_counts
+            .entry(polymer[idx].clone())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+        // pair_counts.entry(polymer[idx + 1].clone()).and_modify(|v| *v += 1).or_insert(1);
+    }
+    pair_counts
+        .entry(polymer[polymer.len() - 1].clone())
+        .and_modify(|v| *v += 1)
+        .or_insert(1);
+    println!("{:?}", pair_counts);
+
+    // Parse the mapping rules
+    let mut mappings = HashMap::new();
+    for line in rule_lines {
+        let line = line
+            .expect("Failed to read line from file")
+            .trim()
+            .to_string();
+        if line.is_empty() {
+            continue;
+        }
+        let mut parts = line
+            .split(" -> ")
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>();
+        let to_insert = parts.pop().expect("Invalid mapping line");
+        let match_pair = parts.pop().expect("Invalid mapping line");
+        mappings.insert(match_pair, to_insert);
+    }
+
+    // Now apply the mapping rules
+    for _ in 0..num_steps {
+        // Clone the original pairs to store as a reference for modified values
+        // Otherwise we update the counts as we iterate which produces inconsistent values
+        let mut pair_counts_mut = pair_counts.clone();
+        for (match_pair, to_insert) in &mappings {
+            if !pair_counts.contains_key(match_pair) {
+                continue;
+            }
+
+            // General Rust TODO: It would be really nice to avoid all of this cloning.
+            // This seems like a code smell that indicates we're not building our ownership hierarchy as well
+            // as we could be...
+
+            // When we divide this monomer with count N, the resulting two monomers will have count N as well
+            let current_count_pair = *pair_counts.get(match_pair).unwrap();
+
+            // Build the two new monomers
+            let (left_part, right_part) = match_pair.split_at(1);
+            let left = left_part.to_string() + to_insert;
+            let right = to_insert.clone() + right_part;
+
+            // Update the counts for monomers
+            pair_counts_mut
+                .entry(left)
+                .and_modify(|v| *v += current_count_pair)
+                .or_insert(current_count_pair);
+            pair_counts_mut
+                .entry(right)
+                .and_modify(|v| *v += current_count_pair)
+                .or_insert(current_count_pair);
+            // Decrement the original pair that we had, since that monomer is gone now
+            pair_counts_mut
+                .entry(match_pair.clone())
+                .and_modify(|v| *v -= current_count_pair);
+            // In addition to the two new monomers, we'll also get N of the newly inserted value
+            pair_counts_mut
+                .entry(to_insert.clone())
+                .and_modify(|v| *v += current_count_pair)
+                .or_insert(current_count_pair);
+        }
+        // We're done modifying, so we can store the modified counts back in the original variable
+        pair_counts = pair_counts_mut;
+    }
+
+    // Get the counts of each building-block (excluding monomers)
+    let mut v = Vec::new();
+    for (key, value) in pair_counts {
+        if key.len() != 1 {
+            continue;
+        }
+        v.push(value);
+    }
+    v
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::solution;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution("inputs/example.txt", 40), 2188189693529);
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt", 40), 4807056953866);
+    }
+}
+
+/// Parse a polymer creation template and return the final polymer chain after N steps, reading the starting polymer
+/// and the insertion rules from separate files.
+///
+/// Works as `solution`, but the template file contains only the starting polymer (eg. `NNCB`) and the rules file
+/// contains only the insertion rules (eg. `CH -> B`), so the same rules can be reused across many templates.
+///
+/// # Arguments
+///
+/// * `template_path` - The input file path containing the starting polymer.
+/// * `rules_path` - The input file path containing the insertion rules.
+/// * `num_steps` - The number of times to apply insertion rules
+///
+/// # Returns
+///
+/// The quantity of the most common element minus the quantity of the least common element after N steps.
+pub fn solution_split(template_path: &str, rules_path: &str, num_steps: usize) -> usize {
+    let template = get_buf_reader(template_path)
+        .lines()
+        .map(|line| line.expect("Failed to read line from file"))
+        .find(|line| !line.trim().is_empty())
+        .expect("Empty file found.");
+    apply_rules(
+        template.trim(),
+        get_buf_reader(rules_path).lines(),
+        num_steps,
+    )
+}
+
+#[cfg(test)]
+mod test_solution_split {
+    use crate::{solution, solution_split};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution_split(
+                "inputs/example_template.txt",
+                "inputs/example_rules.txt",
+                10
+            ),
+            1588
+        );
+    }
+
+    #[test]
+    fn example_matches_single_file() {
+        assert_eq!(
+            solution_split(
+                "inputs/example_template.txt",
+                "inputs/example_rules.txt",
+                40
+            ),
+            solution("inputs/example.txt", 40)
+        );
+    }
+}
+
+/// Parse a polymer creation template and return the length of the final polymer chain after N steps.
+///
+/// The length is the sum of the element counts, so the polymer is never built - every step inserts one element
+/// between each pair, so a template of length L grows to `2^N * (L - 1) + 1` elements.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the polymer rules.
+/// * `num_steps` - The number of times to apply insertion rules
+///
+/// # Returns
+///
+/// The total number of elements in the polymer after N steps.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The example template `NNCB` has length 4, so after 10 steps the polymer has `2^10 * 3 + 1 = 3073` elements.
+pub fn polymer_length(input_path: &str, num_steps: usize) -> u128 {
+    let reader = get_buf_reader(input_path);
+    let mut lines = reader.lines();
+    let template = lines
+        .next()
+        .expect("Empty file found.")
+        .expect("Empty file found.");
+    element_counts(&template, lines, num_steps).iter().sum()
+}
+
+#[cfg(test)]
+mod test_polymer_length {
+    use crate::polymer_length;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(polymer_length("inputs/example.txt", 10), 3073);
+    }
+
+    #[test]
+    fn no_steps_is_template() {
+        assert_eq!(polymer_length("inputs/example.txt", 0), 4);
+    }
+
+    #[test]
+    fn huge_length_handled() {
+        // Far longer than could ever be built as a string, or counted in a u64
+        assert_eq!(
+            polymer_length("inputs/example.txt", 100),
+            3 * (1 << 100) + 1
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day_15::solution;
+use std::hint::black_box;
+
+/// Benchmark the lowest cost path search on the challenge grid, both as-is and repeated 5 times.
+fn bench_solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-15");
+    group.throughput(Throughput::Elements(1));
+    group.sample_size(10);
+    for repetitions in [1, 5] {
+        group.bench_function(format!("challenge x{}", repetitions), |b| {
+            b.iter(|| {
+                solution(
+                    black_box("inputs/challenge.txt"),
+                    black_box(repetitions),
+                    false,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_solution);
+criterion_main!(benches);
@@ -0,0 +1,388 @@
+use shared::grid::{dijkstra, dijkstra_distances, Grid};
+use shared::parse::parse_digit_line;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Error};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// A grid of risk levels, where each point is indexed row by row with 0 being the top left.
+pub struct Field {
+    spaces: Vec<usize>,
+    width: usize,
+}
+
+impl From<Grid<usize>> for Field {
+    fn from(grid: Grid<usize>) -> Field {
+        Field {
+            spaces: grid.values,
+            width: grid.width,
+        }
+    }
+}
+impl Field {
+    /// Method used to parse a single iteration of the input file
+    fn _parse_line(line_number: usize, line: Result<String, Error>) -> Vec<usize> {
+        let line = line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_number, e));
+        parse_digit_line::<usize>(&line)
+            .unwrap_or_else(|_| panic!("Failed to parse line {}: {}", line_number, line))
+    }
+
+    /// Parse a Field from a buffered reader of numbers.
+    fn from_reader<R: BufRead>(reader: R, repetitions: usize) -> Field {
+        let mut lines = reader.lines().enumerate();
+        let mut inputs = Vec::new();
+        // Parse just the first line to determine the overall width of the inputs
+        let (_, first) = lines.next().expect("No lines found in input.");
+        let line = Field::_parse_line(1, first);
+        inputs.extend(line.clone());
+
+        /// Method to scale lines as we repeat out and down
+        fn scale_line(scale: usize, line: &[usize]) -> Vec<usize> {
+            line.iter()
+                .map(|v| ((v + scale - 1) % 9) + 1)
+                .collect::<Vec<usize>>()
+        }
+
+        // Repeat this first line horizontally 5 times, scaling by 1 each time
+        for scale in 1..repetitions {
+            inputs.extend(scale_line(scale, &line));
+        }
+        let array_width = inputs.len();
+
+        // Parse the remaining lines of the original grid, extending horizontally N repetitions each time
+        for (line_idx, line) in lines {
+            let line = Field::_parse_line(line_idx + 1, line);
+            inputs.extend(line.clone());
+            for scale in 1..repetitions {
+                inputs.extend(scale_line(scale, &line));
+            }
+        }
+        // Now we have the entire grid scaled horizontally, scale it vertically as well
+        let line = inputs.clone();
+        for scale in 1..repetitions {
+            inputs.extend(scale_line(scale, &line));
+        }
+
+        Field {
+            spaces: inputs,
+            width: array_width,
+        }
+    }
+
+    /// Expand the field in memory, repeating it N times horizontally and vertically and increasing
+    /// the cost by 1 per repetition (cost wrapping back to 1 when over 9).
+    fn repeat(&self, repetitions: usize) -> Field {
+        let height = self.len() / self.width;
+        let width = self.width * repetitions;
+        let mut spaces = Vec::with_capacity(self.len() * repetitions * repetitions);
+        for tile_row in 0..repetitions {
+            for row in 0..height {
+                for tile_col in 0..repetitions {
+                    let scale = tile_row + tile_col;
+                    spaces.extend(
+                        self.spaces[row * self.width..(row + 1) * self.width]
+                            .iter()
+                            .map(|v| ((v + scale - 1) % 9) + 1),
+                    );
+                }
+            }
+        }
+        Field { spaces, width }
+    }
+
+    /// Return the count of elements in the Field.
+    fn len(&self) -> usize {
+        self.spaces.len()
+    }
+
+    /// Return the value of the field at the given index.
+    fn get(&self, idx: usize) -> usize {
+        self.spaces[idx]
+    }
+
+    /// Return the indexes of all points adjacent to the given point, including diagonally adjacent points if asked.
+    fn neighbors(&self, idx: usize, diagonal: bool) -> Vec<usize> {
+        let mut neighbors = Vec::new();
+        let has_above = idx >= self.width;
+        let has_left = !idx.is_multiple_of(self.width);
+        let has_right = idx % self.width != self.width - 1;
+        let has_below = idx < self.len() - self.width;
+        // Check the value above us
+        if has_above {
+            neighbors.push(idx - self.width);
+        }
+        // Check the value to the left of us
+        if has_left {
+            neighbors.push(idx - 1);
+        }
+        // Check the value to the right of us
+        if has_right {
+            neighbors.push(idx + 1);
+        }
+        // Check the value below us
+        if has_below {
+            neighbors.push(idx + self.width);
+        }
+        if !diagonal {
+            return neighbors;
+        }
+        // Check the values at each of our corners
+        if has_above && has_left {
+            neighbors.push(idx - 1 - self.width);
+        }
+        if has_above && has_right {
+            neighbors.push(idx + 1 - self.width);
+        }
+        if has_below && has_left {
+            neighbors.push(idx - 1 + self.width);
+        }
+        if has_below && has_right {
+            neighbors.push(idx + 1 + self.width);
+        }
+        neighbors
+    }
+
+    /// Find the cost of the lowest cost path between two points in the field, not counting the cost of the start.
+    ///
+    /// Uses Dijkstra's algorithm, returning None if the goal can't be reached from the start.
+    pub fn min_cost_between(&self, start: usize, goal: usize, diagonal: bool) -> Option<usize> {
+        dijkstra(
+            start,
+            |idx| self.neighbors(idx, diagonal),
+            |idx| self.get(idx),
+            goal,
+        )
+    }
+
+    /// Find the cost of the lowest cost path from the top left to every point in the field, not counting the cost of
+    /// the top left itself.
+    ///
+    /// Uses Dijkstra's algorithm, searching the whole field - every point of a field can be reached from the top left.
+    pub fn distance_map(&self, diagonal: bool) -> Vec<usize> {
+        let distances =
+            dijkstra_distances(0, |idx| self.neighbors(idx, diagonal), |idx| self.get(idx));
+        (0..self.len())
+            .map(|idx| {
+                *distances
+                    .get(&idx)
+                    .unwrap_or_else(|| panic!("No path from the top left to {}.", idx))
+            })
+            .collect()
+    }
+
+    /// Find the cost of the lowest cost path from the top left to the bottom right of the field.
+    fn get_min_cost_dijkstra(&self, diagonal: bool) -> usize {
+        *self
+            .distance_map(diagonal)
+            .last()
+            .expect("No path from the top left to the bottom right of the field.")
+    }
+}
+
+#[cfg(test)]
+mod test_field {
+    use crate::Field;
+    use shared::grid::Grid;
+    use std::io::Cursor;
+
+    const EXAMPLE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    fn field(grid: &str) -> Field {
+        grid.parse::<Grid<usize>>().unwrap().into()
+    }
+
+    #[test]
+    fn in_memory_reader_correct() {
+        let field = Field::from_reader(Cursor::new(EXAMPLE), 1);
+        assert_eq!(field.get_min_cost_dijkstra(false), 40);
+    }
+
+    #[test]
+    fn small_grid_correct() {
+        let field = field("116\n138\n213");
+        // Down the left side and along the bottom costs 1 + 2 + 1 + 3
+        assert_eq!(field.get_min_cost_dijkstra(false), 7);
+        // Down, then diagonally to the bottom middle, then right costs 1 + 1 + 3
+        assert_eq!(field.get_min_cost_dijkstra(true), 5);
+    }
+
+    #[test]
+    fn diagonal_route_no_worse() {
+        let field = field(EXAMPLE);
+        assert!(field.get_min_cost_dijkstra(true) <= field.get_min_cost_dijkstra(false));
+        // (1, 1) -> (2, 2) is a single diagonal step
+        assert_eq!(field.min_cost_between(11, 22, true), Some(3));
+    }
+
+    #[test]
+    fn interior_route_correct() {
+        let field = field(EXAMPLE);
+        // (1, 1) -> (1, 2) -> (2, 2) costs 1 + 3
+        assert_eq!(field.min_cost_between(11, 22, false), Some(4));
+        assert_eq!(field.min_cost_between(22, 22, false), Some(0));
+    }
+
+    #[test]
+    fn repeat_matches_parsed() {
+        let parsed = Field::from_reader(Cursor::new(EXAMPLE), 5);
+        let repeated = Field::from_reader(Cursor::new(EXAMPLE), 1).repeat(5);
+        assert_eq!(repeated.width, parsed.width);
+        assert_eq!(repeated.spaces, parsed.spaces);
+    }
+
+    #[test]
+    fn distance_map_correct() {
+        let distances = field(EXAMPLE).distance_map(false);
+        assert_eq!(distances.len(), 100);
+        assert_eq!(distances[0], 0);
+        assert_eq!(distances[99], 40);
+        // (1, 0) is a single step right, and (1, 1) costs 1 + 3 either way
+        assert_eq!(distances[1], 1);
+        assert_eq!(distances[11], 4);
+    }
+
+    #[test]
+    fn distance_map_diagonal_correct() {
+        assert_eq!(
+            field("116\n138\n213").distance_map(true),
+            vec![0, 1, 7, 1, 3, 9, 3, 2, 5]
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_goal_unreachable() {
+        let field = field(EXAMPLE);
+        assert_eq!(field.min_cost_between(0, 1000, false), None);
+    }
+}
+
+/// Calculate the lowest cost path between the top left and bottom right corners of a grid.
+///
+/// Example grid:
+///
+/// ```text
+/// 1163751742
+/// 1381373672
+/// 2136511328
+/// 3694931569
+/// 7463417111
+/// 1319128137
+/// 1359912421
+/// 3125421639
+/// 1293138521
+/// 2311944581
+/// ```
+///
+/// Optionally repeat the input grid N times horizontally and vertically, increasing the cost
+/// per repitition by 1 each time (cost wrapping back to 1 when over 9).
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the grid to traverse.
+/// * `repetitions` - Number of times to repeat the grid vertically / horizontally.
+/// * `diagonal` - Allow moving diagonally as well as horizontally and vertically.
+///
+/// # Returns
+///
+/// The cost of the lowest cost path.
+pub fn solution(input_path: &str, repetitions: usize, diagonal: bool) -> usize {
+    let reader = get_buf_reader(input_path);
+    let f = Field::from_reader(reader, repetitions);
+    f.get_min_cost_dijkstra(diagonal)
+}
+
+/// Calculate the lowest cost path for several repetitions of a grid, reading the input only once.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the grid to traverse.
+/// * `repetitions` - Each number of times to repeat the grid vertically / horizontally.
+///
+/// # Returns
+///
+/// The cost of the lowest cost path for each repetition, in order.
+pub fn solutions(input_path: &str, repetitions: &[usize]) -> Vec<usize> {
+    let reader = get_buf_reader(input_path);
+    let f = Field::from_reader(reader, 1);
+    repetitions
+        .iter()
+        .map(|&r| f.repeat(r).get_min_cost_dijkstra(false))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, solutions};
+
+    #[test]
+    fn example_correct_both() {
+        assert_eq!(solutions("inputs/example.txt", &[1, 5]), vec![40, 315]);
+    }
+
+    #[test]
+    fn example_correct_small() {
+        assert_eq!(solution("inputs/example.txt", 1, false), 40);
+    }
+
+    #[test]
+    fn example_correct_large() {
+        assert_eq!(solution("inputs/example.txt", 5, false), 315);
+    }
+
+    #[test]
+    fn example_diagonal_correct() {
+        let diagonal = solution("inputs/example.txt", 1, true);
+        assert!(diagonal <= 40);
+        assert_eq!(diagonal, 20);
+    }
+
+    #[test]
+    fn question_correct_small() {
+        assert_eq!(solution("inputs/challenge.txt", 1, false), 656);
+    }
+    #[test]
+    fn question_correct_large() {
+        assert_eq!(solution("inputs/challenge.txt", 5, false), 2979);
+    }
+}
@@ -0,0 +1,16 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day_16::solution;
+use std::hint::black_box;
+
+/// Benchmark decoding and evaluating the challenge transmission.
+fn bench_solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-16");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("challenge", |b| {
+        b.iter(|| solution(black_box("inputs/challenge.txt")))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_solution);
+criterion_main!(benches);
@@ -0,0 +1,1013 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+use std::vec::IntoIter;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Errors encountered while decoding a transmission.
+#[derive(Debug, PartialEq)]
+pub enum PacketError {
+    /// A binary transmission contained a character other than `0` or `1`.
+    InvalidBit(char),
+    /// A hex transmission contained a character other than a hex digit.
+    InvalidHex(char),
+    /// An operator packet contained the wrong number of sub-packets.
+    OperandCount {
+        id: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A transmission contained no packets to evaluate.
+    NoPackets,
+    /// A packet had a type id that isn't an operator or a literal.
+    UnknownType(usize),
+    /// A packet ran out of bits part way through being parsed.
+    Truncated { needed: usize, available: usize },
+    /// A sub-packet ran past the end of its operator packet's bit length.
+    LengthOverrun { length: usize, read: usize },
+}
+
+impl fmt::Display for PacketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PacketError::InvalidBit(c) => write!(f, "Invalid bit in transmission: {:?}", c),
+            PacketError::InvalidHex(c) => write!(f, "Invalid hex digit in transmission: {:?}", c),
+            PacketError::OperandCount {
+                id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Operator packet with type id {} expects {} sub-packets, found {}",
+                id, expected, found
+            ),
+            PacketError::NoPackets => write!(f, "Transmission contained no packets"),
+            PacketError::UnknownType(id) => write!(f, "Unknown packet type id: {}", id),
+            PacketError::Truncated { needed, available } => write!(
+                f,
+                "Packet needed {} more bits, but only {} remain",
+                needed, available
+            ),
+            PacketError::LengthOverrun { length, read } => write!(
+                f,
+                "Operator packet has {} bits of sub-packets, but read {}",
+                length, read
+            ),
+        }
+    }
+}
+
+impl Error for PacketError {}
+
+/// The number of bits in a packet header (3 bit version followed by a 3 bit type id).
+const HEADER_SIZE: usize = 6;
+
+/// The number of bits in the smallest possible packet (a header followed by a single 5 bit literal group).
+const MIN_PACKET_SIZE: usize = HEADER_SIZE + 5;
+
+/// The operation a packet performs, as encoded by its type id.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Literal,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl Op {
+    /// Decode a packet type id, returning an error if the id isn't between 0 and 7.
+    fn from_id(id: usize) -> Result<Op, PacketError> {
+        let op = match id {
+            0 => Op::Sum,
+            1 => Op::Product,
+            2 => Op::Min,
+            3 => Op::Max,
+            4 => Op::Literal,
+            5 => Op::Gt,
+            6 => Op::Lt,
+            7 => Op::Eq,
+            _ => return Err(PacketError::UnknownType(id)),
+        };
+        Ok(op)
+    }
+
+    /// Return the type id that encodes this operation.
+    fn id(&self) -> usize {
+        match self {
+            Op::Sum => 0,
+            Op::Product => 1,
+            Op::Min => 2,
+            Op::Max => 3,
+            Op::Literal => 4,
+            Op::Gt => 5,
+            Op::Lt => 6,
+            Op::Eq => 7,
+        }
+    }
+
+    /// Return the name of the operation.
+    fn name(&self) -> &str {
+        match self {
+            Op::Sum => "sum",
+            Op::Product => "product",
+            Op::Min => "minimum",
+            Op::Max => "maximum",
+            Op::Literal => "literal",
+            Op::Gt => "greater than",
+            Op::Lt => "less than",
+            Op::Eq => "equal to",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_op {
+    use crate::{Op, PacketError};
+
+    #[test]
+    fn from_id_correct() {
+        let ops = [
+            Op::Sum,
+            Op::Product,
+            Op::Min,
+            Op::Max,
+            Op::Literal,
+            Op::Gt,
+            Op::Lt,
+            Op::Eq,
+        ];
+        for (id, op) in ops.into_iter().enumerate() {
+            assert_eq!(Op::from_id(id), Ok(op));
+            assert_eq!(op.id(), id);
+        }
+    }
+
+    #[test]
+    fn from_id_unknown() {
+        assert_eq!(Op::from_id(8), Err(PacketError::UnknownType(8)));
+    }
+}
+
+/// A single packet, along with all of the sub-packets it contains.
+#[derive(Debug)]
+struct Packet {
+    op: Op,
+    version: usize,
+    value: Option<usize>,
+    children: Vec<Packet>,
+}
+
+impl Packet {
+    /// Apply this packet's operator to the values of its sub-packets.
+    ///
+    /// Comparison operators (greater than, less than, equal to) must have exactly two sub-packets.
+    fn comp(&self, others: &[usize]) -> Result<usize, PacketError> {
+        if matches!(self.op, Op::Gt | Op::Lt | Op::Eq) && others.len() != 2 {
+            return Err(PacketError::OperandCount {
+                id: self.op.id(),
+                expected: 2,
+                found: others.len(),
+            });
+        }
+        // Min and max have no value for an empty operand list, unlike sum (0) and product (1)
+        let no_operands = PacketError::OperandCount {
+            id: self.op.id(),
+            expected: 1,
+            found: 0,
+        };
+        let value = match self.op {
+            Op::Sum => others.iter().sum::<usize>(),
+            Op::Product => others.iter().product(),
+            Op::Min => *others.iter().min().ok_or(no_operands)?,
+            Op::Max => *others.iter().max().ok_or(no_operands)?,
+            Op::Gt => (others[0] > others[1]) as usize,
+            Op::Lt => (others[0] < others[1]) as usize,
+            Op::Eq => (others[0] == others[1]) as usize,
+            // Literals carry their own value, so are never applied to sub-packets
+            Op::Literal => 0,
+        };
+        Ok(value)
+    }
+
+    /// Evaluate the packet - literals are their own value, operators are applied to their evaluated sub-packets.
+    ///
+    /// Sub-packets are evaluated with an explicit stack, so deeply nested packets can't overflow the call stack.
+    fn value(&self) -> Result<usize, PacketError> {
+        // Each entry is a packet along with the values of the sub-packets evaluated so far
+        let mut stack: Vec<(&Packet, Vec<usize>)> = vec![(self, Vec::new())];
+        while let Some((packet, values)) = stack.pop() {
+            if packet.value.is_none() && values.len() < packet.children.len() {
+                // Evaluate the next sub-packet before coming back to this packet
+                let child = &packet.children[values.len()];
+                stack.push((packet, values));
+                stack.push((child, Vec::new()));
+                continue;
+            }
+            let value = match packet.value {
+                Some(value) => value,
+                None => packet.comp(&values)?,
+            };
+            match stack.last_mut() {
+                Some((_, parent_values)) => parent_values.push(value),
+                None => return Ok(value),
+            }
+        }
+        unreachable!("The stack always starts with the packet being evaluated.")
+    }
+
+    /// Return the sum of this packet's version and the versions of all of its sub-packets.
+    fn version_sum(&self) -> usize {
+        self.version + self.children.iter().map(|p| p.version_sum()).sum::<usize>()
+    }
+
+    /// Render the packet and all of its sub-packets as an indented tree, one packet per line.
+    fn describe(&self) -> String {
+        let mut description = String::new();
+        self.describe_into(&mut description, 0);
+        description
+    }
+
+    /// Append the description of this packet to a string, indented by the packet's depth in the tree.
+    fn describe_into(&self, description: &mut String, depth: usize) {
+        let detail = match self.value {
+            Some(value) => format!("literal {}", value),
+            None => format!("{} of {} sub-packets", self.op.name(), self.children.len()),
+        };
+        description.push_str(&format!(
+            "{}version {}, type {}: {}\n",
+            "  ".repeat(depth),
+            self.version,
+            self.op.id(),
+            detail
+        ));
+        for child in &self.children {
+            child.describe_into(description, depth + 1);
+        }
+    }
+}
+
+impl Drop for Packet {
+    /// Drop sub-packets with an explicit stack, so deeply nested packets can't overflow the call stack.
+    fn drop(&mut self) {
+        let mut to_drop = std::mem::take(&mut self.children);
+        while let Some(mut packet) = to_drop.pop() {
+            to_drop.append(&mut packet.children);
+        }
+    }
+}
+
+/// An operator packet that is still having its sub-packets parsed.
+struct PartialPacket {
+    op: Op,
+    version: usize,
+    mode: usize,
+    /// The bits (mode 0) or sub-packets (mode 1) the packet's length field declared.
+    length: usize,
+    /// The bits (mode 0) or sub-packets (mode 1) left to parse.
+    remaining: usize,
+    bits_read: usize,
+    children: Vec<Packet>,
+}
+
+#[cfg(test)]
+mod test_packet {
+    use crate::PacketSequence;
+
+    #[test]
+    fn describe_operator() {
+        let (packet, _) = PacketSequence::new("C200B40A82".to_string())
+            .unwrap()
+            .parse_packet()
+            .unwrap();
+        assert_eq!(
+            packet.describe(),
+            "version 6, type 0: sum of 2 sub-packets\n  version 6, type 4: literal 1\n  version 2, type 4: literal 2\n"
+        );
+    }
+}
+
+struct Literal {
+    value: usize,
+    bits_read: usize,
+}
+
+/// A stream of bits containing one or more encoded packets.
+#[derive(Debug)]
+pub struct PacketSequence {
+    it: IntoIter<String>,
+    total_bits: usize,
+    /// The position of the last `1` bit, where every bit after it is zero padding.
+    last_one: Option<usize>,
+}
+
+impl PacketSequence {
+    /// Return a mapping of hexadecimal characters to their base 2 encoding as strings.
+    fn _hex() -> HashMap<String, String> {
+        [
+            ("0", "0000"),
+            ("1", "0001"),
+            ("2", "0010"),
+            ("3", "0011"),
+            ("4", "0100"),
+            ("5", "0101"),
+            ("6", "0110"),
+            ("7", "0111"),
+            ("8", "1000"),
+            ("9", "1001"),
+            ("A", "1010"),
+            ("B", "1011"),
+            ("C", "1100"),
+            ("D", "1101"),
+            ("E", "1110"),
+            ("F", "1111"),
+        ]
+        .iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect()
+    }
+
+    /// Parse an integer from a vector of bits.
+    fn _parse_int(bytes: Vec<String>) -> usize {
+        let bytestr = bytes.join("");
+        // println!("Parsed bytes: {}", bytestr);
+        usize::from_str_radix(bytestr.as_str(), 2).expect("Failed to parse bytes as int.")
+    }
+
+    /// Take `take` bits from the iterator of bits, returning an error if fewer than that remain.
+    fn _take_bits(&mut self, take: usize) -> Result<Vec<String>, PacketError> {
+        if self.it.len() < take {
+            return Err(PacketError::Truncated {
+                needed: take,
+                available: self.it.len(),
+            });
+        }
+        Ok(self.it.by_ref().take(take).collect::<Vec<String>>())
+    }
+
+    /// Take a single integer of size `take` bytes from the iterator of bits.
+    fn _take_int(&mut self, take: usize) -> Result<usize, PacketError> {
+        let parts = self._take_bits(take)?;
+        Ok(PacketSequence::_parse_int(parts))
+    }
+
+    /// Take a 3 bit packet type id from the iterator of bits, decoding it into the operation it represents.
+    fn _take_op(&mut self) -> Result<Op, PacketError> {
+        let id = self._take_int(3)?;
+        Op::from_id(id)
+    }
+
+    /// Take a literal value with 5 bit encoding from the iterator of unknown total size.
+    fn _take_literal(&mut self) -> Result<Literal, PacketError> {
+        let mut bits_read = 0;
+        let mut has_more_to_read = true;
+        let mut target_bits = Vec::new();
+        while has_more_to_read {
+            has_more_to_read = self._take_int(1)? == 1;
+            target_bits.extend(self._take_bits(4)?);
+            bits_read += 5;
+        }
+        Ok(Literal {
+            value: PacketSequence::_parse_int(target_bits),
+            bits_read,
+        })
+    }
+
+    /// Take a packet, and all of the sub-packets it contains, out of the PacketSequence.
+    ///
+    /// Returns the packet tree along with the number of bits read to parse it, or an error if the packet is malformed.
+    fn parse_packet(&mut self) -> Result<(Packet, usize), PacketError> {
+        let version = self._take_int(3)?;
+        let op = self._take_op()?;
+        let mut bits_read = HEADER_SIZE;
+        if op == Op::Literal {
+            let lit = self._take_literal()?;
+            let packet = Packet {
+                op,
+                version,
+                value: Some(lit.value),
+                children: Vec::new(),
+            };
+            return Ok((packet, bits_read + lit.bits_read));
+        }
+
+        let mut children = Vec::new();
+        let mode = self._take_int(1)?;
+        if mode == 0 {
+            // Mode 0 packets contain sub-packets totalling a given number of bits
+            let size = self._take_int(15)?;
+            bits_read += 16 + size;
+            let mut to_read = size;
+            while to_read > 0 {
+                let (child, child_bits) = self.parse_packet()?;
+                to_read = to_read
+                    .checked_sub(child_bits)
+                    .ok_or(PacketError::LengthOverrun {
+                        length: size,
+                        read: size - to_read + child_bits,
+                    })?;
+                children.push(child);
+            }
+        } else {
+            // Mode 1 packets contain a given number of sub-packets
+            let size = self._take_int(11)?;
+            bits_read += 12;
+            for _ in 0..size {
+                let (child, child_bits) = self.parse_packet()?;
+                bits_read += child_bits;
+                children.push(child);
+            }
+        }
+        let packet = Packet {
+            op,
+            version,
+            value: None,
+            children,
+        };
+        Ok((packet, bits_read))
+    }
+
+    /// Take a packet, and all of the sub-packets it contains, out of the PacketSequence without recursing.
+    ///
+    /// Produces the same tree as `parse_packet`, but tracks partially parsed operators on an explicit stack so that
+    /// deeply nested packets can't overflow the call stack.
+    fn parse_packet_iterative(&mut self) -> Result<(Packet, usize), PacketError> {
+        let mut stack: Vec<PartialPacket> = Vec::new();
+        loop {
+            let version = self._take_int(3)?;
+            let op = self._take_op()?;
+            let (mut packet, mut bits_read) = if op == Op::Literal {
+                let lit = self._take_literal()?;
+                let packet = Packet {
+                    op,
+                    version,
+                    value: Some(lit.value),
+                    children: Vec::new(),
+                };
+                (packet, HEADER_SIZE + lit.bits_read)
+            } else {
+                let mode = self._take_int(1)?;
+                let (remaining, bits_read) = if mode == 0 {
+                    (self._take_int(15)?, HEADER_SIZE + 16)
+                } else {
+                    (self._take_int(11)?, HEADER_SIZE + 12)
+                };
+                if remaining > 0 {
+                    // Parse the sub-packets before finishing this packet
+                    stack.push(PartialPacket {
+                        op,
+                        version,
+                        mode,
+                        length: remaining,
+                        remaining,
+                        bits_read,
+                        children: Vec::new(),
+                    });
+                    continue;
+                }
+                let packet = Packet {
+                    op,
+                    version,
+                    value: None,
+                    children: Vec::new(),
+                };
+                (packet, bits_read)
+            };
+
+            // Hand the finished packet up to its parent, finishing any parents that have all of their sub-packets
+            loop {
+                let Some(parent) = stack.last_mut() else {
+                    return Ok((packet, bits_read));
+                };
+                parent.children.push(packet);
+                parent.bits_read += bits_read;
+                let consumed = if parent.mode == 0 { bits_read } else { 1 };
+                parent.remaining =
+                    parent
+                        .remaining
+                        .checked_sub(consumed)
+                        .ok_or(PacketError::LengthOverrun {
+                            length: parent.length,
+                            read: parent.length - parent.remaining + consumed,
+                        })?;
+                if parent.remaining > 0 {
+                    break;
+                }
+                let parent = stack.pop().unwrap();
+                bits_read = parent.bits_read;
+                packet = Packet {
+                    op: parent.op,
+                    version: parent.version,
+                    value: None,
+                    children: parent.children,
+                };
+            }
+        }
+    }
+
+    /// Parse all of the packets that are contained in a hex encoded string.
+    ///
+    /// Surrounding whitespace is ignored and lowercase digits are accepted.
+    pub fn new(hex: String) -> Result<PacketSequence, PacketError> {
+        let hex = hex.trim().to_uppercase();
+        if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(PacketError::InvalidHex(c));
+        }
+        let hex_mapping = PacketSequence::_hex();
+        let bits = hex
+            .split("")
+            .filter(|s| s != &"")
+            .map(|c| hex_mapping[c].as_str())
+            .collect::<String>();
+        PacketSequence::from_bits(&bits)
+    }
+
+    /// Parse all of the packets that are contained in a binary string of `0` and `1` characters.
+    pub fn from_bits(bits: &str) -> Result<PacketSequence, PacketError> {
+        if let Some(c) = bits.chars().find(|c| *c != '0' && *c != '1') {
+            return Err(PacketError::InvalidBit(c));
+        }
+        let last_one = bits.rfind('1');
+        let bits = bits.chars().map(|c| c.to_string()).collect::<Vec<String>>();
+        Ok(PacketSequence {
+            total_bits: bits.len(),
+            it: bits.into_iter(),
+            last_one,
+        })
+    }
+
+    /// Take the next top-level packet out of the PacketSequence and evaluate its total value.
+    pub fn evaluate(&mut self) -> Result<usize, PacketError> {
+        let (packet, _) = self.parse_packet()?;
+        packet.value()
+    }
+
+    /// Take the next top-level packet out of the PacketSequence and evaluate its total value, without recursing.
+    ///
+    /// Use this over `evaluate` for transmissions with deeply nested packets.
+    pub fn evaluate_iterative(&mut self) -> Result<usize, PacketError> {
+        let (packet, _) = self.parse_packet_iterative()?;
+        packet.value()
+    }
+
+    /// Take the next top-level packet out of the PacketSequence and sum the versions of it and all its sub-packets.
+    pub fn version_sum(&mut self) -> Result<usize, PacketError> {
+        let (packet, _) = self.parse_packet()?;
+        Ok(packet.version_sum())
+    }
+
+    /// Take the next top-level packet out of the PacketSequence and describe its tree of sub-packets.
+    pub fn describe(&mut self) -> Result<String, PacketError> {
+        let (packet, _) = self.parse_packet()?;
+        Ok(packet.describe())
+    }
+
+    /// Take every top-level packet out of the PacketSequence and evaluate each of them in order.
+    ///
+    /// Packets are taken until fewer bits than a packet header remain, or the remaining bits are all zero padding.
+    /// Returns an error if a packet runs out of bits before it is fully parsed.
+    pub fn evaluate_all(&mut self) -> Result<Vec<usize>, PacketError> {
+        let mut values = Vec::new();
+        while self.it.len() >= HEADER_SIZE
+            && self.last_one.is_some_and(|idx| idx >= self.bits_read())
+        {
+            values.push(self.evaluate()?);
+        }
+        Ok(values)
+    }
+
+    /// Return the number of bits taken out of the PacketSequence so far, not counting any unread padding.
+    pub fn bits_read(&self) -> usize {
+        self.total_bits - self.it.len()
+    }
+
+    /// Take every top-level packet out of the PacketSequence, returning the (min, max, sum) of their values.
+    pub fn summarize(&mut self) -> Result<(usize, usize, usize), PacketError> {
+        let values = self.evaluate_all()?;
+        let min = *values.iter().min().ok_or(PacketError::NoPackets)?;
+        let max = *values.iter().max().ok_or(PacketError::NoPackets)?;
+        Ok((min, max, values.iter().sum()))
+    }
+}
+
+#[cfg(test)]
+mod test_packet_sequence {
+    use crate::{PacketError, PacketSequence};
+
+    #[test]
+    fn version_sum_correct() {
+        let examples = [
+            ("D2FE28", 6),
+            ("38006F45291200", 9),
+            ("EE00D40C823060", 14),
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+            ("A0016C880162017C3686B18A3D4780", 31),
+        ];
+        for (hex, expected) in examples {
+            assert_eq!(
+                PacketSequence::new(hex.to_string()).unwrap().version_sum(),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn from_bits_literal() {
+        // 110 100 10111 11110 00101 (literal 2021) + 000 padding
+        let mut seq = PacketSequence::from_bits("110100101111111000101000").unwrap();
+        assert_eq!(seq.evaluate(), Ok(2021));
+    }
+
+    #[test]
+    fn from_bits_invalid_char() {
+        assert_eq!(
+            PacketSequence::from_bits("1101x0").unwrap_err(),
+            PacketError::InvalidBit('x')
+        );
+    }
+
+    #[test]
+    fn new_normalizes_hex() {
+        let mut seq = PacketSequence::new("d2fe28\n".to_string()).unwrap();
+        assert_eq!(seq.evaluate(), Ok(2021));
+    }
+
+    #[test]
+    fn new_invalid_char() {
+        assert_eq!(
+            PacketSequence::new("D2FG28".to_string()).unwrap_err(),
+            PacketError::InvalidHex('G')
+        );
+    }
+
+    #[test]
+    fn evaluate_all_single_packet() {
+        let mut seq = PacketSequence::new("D2FE28".to_string()).unwrap();
+        assert_eq!(seq.evaluate_all(), Ok(vec![2021]));
+    }
+
+    #[test]
+    fn evaluate_all_concatenated_literals() {
+        // 001 100 00101 (literal 5) + 010 100 00111 (literal 7) + 00 padding
+        let mut seq = PacketSequence::new("30AA1C".to_string()).unwrap();
+        assert_eq!(seq.evaluate_all(), Ok(vec![5, 7]));
+    }
+
+    #[test]
+    fn bits_read_literal() {
+        // 110 100 10111 11110 00101 (literal 2021) + 000 padding
+        let mut seq = PacketSequence::new("D2FE28".to_string()).unwrap();
+        assert_eq!(seq.bits_read(), 0);
+        assert_eq!(seq.evaluate(), Ok(2021));
+        assert_eq!(seq.bits_read(), 21);
+    }
+
+    #[test]
+    fn bits_read_operator() {
+        // 001 110 0 000000000011011 (27 bits of sub-packets) + 110 100 01010 (literal 10)
+        // + 010 100 10001 00100 (literal 20) + 0000000 padding
+        let mut seq = PacketSequence::new("38006F45291200".to_string()).unwrap();
+        assert_eq!(seq.evaluate(), Ok(1));
+        assert_eq!(seq.bits_read(), 22 + 27);
+    }
+
+    #[test]
+    fn bits_read_concatenated_literals() {
+        let mut seq = PacketSequence::new("30AA1C".to_string()).unwrap();
+        assert_eq!(seq.evaluate_all(), Ok(vec![5, 7]));
+        assert_eq!(seq.bits_read(), 22);
+    }
+
+    #[test]
+    fn summarize_concatenated_literals() {
+        // 001 100 00101 (literal 5) + 010 100 00111 (literal 7) + 011 100 00011 (literal 3) + 000 padding
+        let mut seq = PacketSequence::from_bits("001100001010101000011101110000011000").unwrap();
+        assert_eq!(seq.summarize(), Ok((3, 7, 15)));
+    }
+
+    #[test]
+    fn truncated_literal_fails() {
+        // 110 100 10111 11110 0 010 (literal missing its last bit)
+        let mut seq = PacketSequence::new("D2FE2".to_string()).unwrap();
+        assert_eq!(
+            seq.evaluate_all(),
+            Err(PacketError::Truncated {
+                needed: 4,
+                available: 3
+            })
+        );
+        let mut seq = PacketSequence::new("D2".to_string()).unwrap();
+        assert_eq!(
+            seq.evaluate(),
+            Err(PacketError::Truncated {
+                needed: 4,
+                available: 1
+            })
+        );
+    }
+
+    #[test]
+    fn truncated_operator_fails() {
+        // 001 110 0 000000000011011 (27 bits of sub-packets) + 110 100 01010 (literal 10) + 010 (cut short)
+        let mut seq = PacketSequence::new("38006F452".to_string()).unwrap();
+        assert_eq!(
+            seq.evaluate_all(),
+            Err(PacketError::Truncated {
+                needed: 3,
+                available: 0
+            })
+        );
+        let mut seq = PacketSequence::new("38006F452".to_string()).unwrap();
+        assert!(matches!(
+            seq.evaluate_iterative(),
+            Err(PacketError::Truncated { .. })
+        ));
+    }
+
+    #[test]
+    fn sub_packet_length_overrun_fails() {
+        // 000 000 0 000000000001000 (sum, 8 bits of sub-packets) + 000 100 00001 (literal 1, 11 bits)
+        let bits = "0000000".to_string() + "000000000001000" + "00010000001";
+        let overrun = || PacketError::LengthOverrun {
+            length: 8,
+            read: 11,
+        };
+        let mut seq = PacketSequence::from_bits(&bits).unwrap();
+        assert_eq!(seq.evaluate(), Err(overrun()));
+        let mut seq = PacketSequence::from_bits(&bits).unwrap();
+        assert_eq!(seq.evaluate_iterative(), Err(overrun()));
+    }
+
+    #[test]
+    fn summarize_empty_fails() {
+        let mut seq = PacketSequence::from_bits("000000").unwrap();
+        assert_eq!(seq.summarize(), Err(PacketError::NoPackets));
+    }
+
+    /// Build a binary transmission of `depth` nested single sub-packet sum operators around the literal 1.
+    fn nested_bits(depth: usize) -> String {
+        // 000 000 1 00000000001 (sum, 1 sub-packet) ... 000 100 00001 (literal 1)
+        "000000100000000001".repeat(depth) + "00010000001"
+    }
+
+    #[test]
+    fn iterative_matches_recursive() {
+        let examples = [
+            "C200B40A82",
+            "04005AC33890",
+            "880086C3E88112",
+            "CE00C43D881120",
+            "D8005AC2A8F0",
+            "F600BC2D8F",
+            "9C005AC2F8F0",
+            "9C0141080250320F1802104A08",
+            "A0016C880162017C3686B18A3D4780",
+        ];
+        for hex in examples {
+            let (recursive, recursive_bits) = PacketSequence::new(hex.to_string())
+                .unwrap()
+                .parse_packet()
+                .unwrap();
+            let (iterative, iterative_bits) = PacketSequence::new(hex.to_string())
+                .unwrap()
+                .parse_packet_iterative()
+                .unwrap();
+            assert_eq!(iterative.describe(), recursive.describe());
+            assert_eq!(iterative_bits, recursive_bits);
+        }
+
+        let bits = nested_bits(20);
+        let (recursive, _) = PacketSequence::from_bits(&bits)
+            .unwrap()
+            .parse_packet()
+            .unwrap();
+        let (iterative, _) = PacketSequence::from_bits(&bits)
+            .unwrap()
+            .parse_packet_iterative()
+            .unwrap();
+        assert_eq!(iterative.describe(), recursive.describe());
+    }
+
+    #[test]
+    fn iterative_deep_nesting() {
+        let mut seq = PacketSequence::from_bits(&nested_bits(100_000)).unwrap();
+        assert_eq!(seq.evaluate_iterative(), Ok(1));
+    }
+
+    #[test]
+    fn comparison_operand_count_checked() {
+        // 000 101 1 00000000001 (greater than, 1 sub-packet) + 000 100 00001 (literal 1)
+        let mut seq = PacketSequence::from_bits("00010110000000000100010000001").unwrap();
+        assert_eq!(
+            seq.evaluate(),
+            Err(PacketError::OperandCount {
+                id: 5,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn empty_operands_handled() {
+        // 000 XXX 1 00000000000 (operator with type id XXX, 0 sub-packets)
+        let evaluate = |type_bits: &str| {
+            PacketSequence::from_bits(&format!("000{}100000000000", type_bits))
+                .unwrap()
+                .evaluate()
+        };
+        let no_operands = |id: usize| PacketError::OperandCount {
+            id,
+            expected: 1,
+            found: 0,
+        };
+        assert_eq!(evaluate("000"), Ok(0));
+        assert_eq!(evaluate("001"), Ok(1));
+        assert_eq!(evaluate("010"), Err(no_operands(2)));
+        assert_eq!(evaluate("011"), Err(no_operands(3)));
+    }
+}
+
+/// Evaluate the first packet of a hex encoded transmission.
+///
+/// # Arguments
+///
+/// * `hex` - The hex encoded transmission.
+///
+/// # Returns
+///
+/// The value of the first packet, or an error if the transmission is malformed.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// `C200B40A82` sums 1 and 2, so evaluates to 3.
+pub fn eval_hex(hex: &str) -> Result<usize, PacketError> {
+    PacketSequence::new(hex.to_string())?.evaluate()
+}
+
+/// Sum the versions of the first packet of a hex encoded transmission and all of its sub-packets.
+///
+/// # Arguments
+///
+/// * `hex` - The hex encoded transmission.
+///
+/// # Returns
+///
+/// The sum of the packet versions, or an error if the transmission is malformed.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// `8A004A801A8002F478` has versions 4, 1, 5, and 6, so has a version sum of 16.
+pub fn version_sum_hex(hex: &str) -> Result<usize, PacketError> {
+    PacketSequence::new(hex.to_string())?.version_sum()
+}
+
+#[cfg(test)]
+mod test_hex {
+    use crate::{eval_hex, version_sum_hex, PacketError};
+
+    #[test]
+    fn eval_correct() {
+        assert_eq!(eval_hex("C200B40A82"), Ok(3));
+        assert_eq!(eval_hex("9C0141080250320F1802104A08"), Ok(1));
+    }
+
+    #[test]
+    fn version_sum_correct() {
+        assert_eq!(version_sum_hex("8A004A801A8002F478"), Ok(16));
+        assert_eq!(version_sum_hex("620080001611562C8802118E34"), Ok(12));
+        assert_eq!(version_sum_hex("C0015000016115A2E0802F182340"), Ok(23));
+        assert_eq!(version_sum_hex("A0016C880162017C3686B18A3D4780"), Ok(31));
+    }
+
+    #[test]
+    fn invalid_hex_fail() {
+        assert_eq!(eval_hex("C2Z0"), Err(PacketError::InvalidHex('Z')));
+        assert_eq!(version_sum_hex("C2Z0"), Err(PacketError::InvalidHex('Z')));
+    }
+}
+
+/// Parse a line of a transmission into a PacketSequence, detecting whether it is hex or binary encoded.
+///
+/// Lines made up of only `0` and `1` characters are taken to be binary if they are long enough to hold a packet as
+/// binary, anything else is taken to be hex. A longer hex transmission using only the digits 0 and 1 would be misread,
+/// but any real transmission includes other digits.
+fn detect_and_parse(line: &str) -> Result<PacketSequence, PacketError> {
+    if line.len() >= MIN_PACKET_SIZE && line.chars().all(|c| c == '0' || c == '1') {
+        return PacketSequence::from_bits(line);
+    }
+    PacketSequence::new(line.to_string())
+}
+
+#[cfg(test)]
+mod test_detect_and_parse {
+    use crate::detect_and_parse;
+
+    #[test]
+    fn hex_and_binary_match() {
+        let mut hex = detect_and_parse("D2FE28").unwrap();
+        let mut binary = detect_and_parse("110100101111111000101000").unwrap();
+        assert_eq!(hex.evaluate(), Ok(2021));
+        assert_eq!(binary.evaluate(), Ok(2021));
+    }
+
+    #[test]
+    fn hex_operator_and_binary_match() {
+        let mut hex = detect_and_parse("C200B40A82").unwrap();
+        let mut binary = detect_and_parse("1100001000000000101101000000101010000010").unwrap();
+        assert_eq!(hex.evaluate(), Ok(3));
+        assert_eq!(binary.evaluate(), Ok(3));
+    }
+
+    #[test]
+    fn short_hex_of_zeros_and_ones() {
+        // 000 100 00000 (literal 0) + 10000 padding, which would be too short to hold a packet as binary
+        let mut hex = detect_and_parse("1010").unwrap();
+        assert_eq!(hex.evaluate(), Ok(0));
+        assert_eq!(hex.bits_read(), 11);
+    }
+}
+
+/// Parse a packet of binary into hex, using an unnecessarily complex encoding scheme.
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the packets to parse, one hex or binary transmission per line.
+///
+/// # Returns
+///
+/// The evaluated packet data.
+pub fn solution(input_path: &str) -> Vec<usize> {
+    get_buf_reader(input_path)
+        .lines()
+        .map(|line| {
+            let line = line.expect("Failed to parse line from file.");
+            println!("----------------");
+            println!("Starting hex: {}", line);
+            let mut seq = detect_and_parse(&line)
+                .unwrap_or_else(|e| panic!("Failed to parse packet {}: {}", line, e));
+            seq.evaluate()
+                .unwrap_or_else(|e| panic!("Failed to evaluate packet {}: {}", line, e))
+        })
+        .collect::<Vec<usize>>()
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::solution;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution("inputs/example.txt"),
+            vec![2021, 1, 3, 15, 46, 46, 54],
+        );
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt"), vec![19348959966392]);
+    }
+}
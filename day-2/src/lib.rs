@@ -0,0 +1,411 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+/// Split a line into its direction and movement size, using the line number to report unreadable lines.
+fn parse_movement(line_number: usize, line: &str) -> (&str, i32) {
+    let (key, score) = line
+        .split_once(" ")
+        .unwrap_or_else(|| panic!("Failed to parse line {}: {}", line_number, line));
+    let score = score.parse::<i32>().unwrap_or_else(|_| {
+        panic!(
+            "Failed to parse movement size on line {}: {}",
+            line_number, line
+        )
+    });
+    (key, score)
+}
+
+/// Record movements of forward, up, and down to retrieve the final (horizontal, depth) coordinates of the movements.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the movements
+///
+/// # Returns
+///
+/// The (horizontal, depth) coordinates of the final position.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// For example, the movements:
+///
+/// ```text
+/// forward 5
+/// down 5
+/// forward 8
+/// up 3
+/// down 8
+/// forward 2
+/// ```
+///
+/// Would produce a final position of (15, 10).
+///
+pub fn record_movements(input_path: &str) -> (i32, i32) {
+    let position = apply_movements(read_lines(input_path));
+    (position.horizontal, position.depth)
+}
+
+/// Read the lines of an input file, using the line number to report unreadable lines.
+fn read_lines(input_path: &str) -> impl Iterator<Item = String> {
+    get_buf_reader(input_path)
+        .lines()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_idx + 1, e))
+        })
+}
+
+/// A (horizontal, depth) position reached by following movements.
+#[derive(Debug, PartialEq)]
+pub struct Position {
+    pub horizontal: i32,
+    pub depth: i32,
+}
+
+/// Apply lines of forward, up, and down movements, as described in `record_movements`, starting from (0, 0).
+///
+/// # Arguments
+///
+/// * `lines - The movements to apply, one per line
+///
+/// # Returns
+///
+/// The final position.
+pub fn apply_movements<I: Iterator<Item = String>>(lines: I) -> Position {
+    let (mut horizontal, mut depth) = (0, 0);
+    apply_commands(lines, |key, score| {
+        match key {
+            "forward" => horizontal += score,
+            "up" => depth -= score,
+            "down" => depth += score,
+            _ => return false,
+        }
+        true
+    });
+    Position { horizontal, depth }
+}
+
+/// Parse each line of movements and hand its direction and size to `apply`, which returns false for directions it
+/// doesn't know - panicking with the line number of the unknown direction.
+fn apply_commands<I, F>(lines: I, mut apply: F)
+where
+    I: Iterator<Item = String>,
+    F: FnMut(&str, i32) -> bool,
+{
+    for (line_idx, line) in lines.enumerate() {
+        let line_number = line_idx + 1;
+        let (key, score) = parse_movement(line_number, &line);
+        if !apply(key, score) {
+            panic!("Unknown direction on line {}: {}", line_number, line);
+        }
+    }
+}
+
+/// Record movements of forward, up, and down to retrieve the final (horizontal, depth) coordinates of the movements.
+///
+/// Records movements using *aim* concept, where rather than simply changing directions, up/down movements just adjust
+/// an aim factor, with only forward movements impacting depth.
+///
+/// * down X increases aim by X units.
+/// * up X decreases aim by X units.
+/// * forward X does two things:
+///   * It increases horizontal position by X units.
+///   * It increases depth by your aim multiplied by X.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the movements
+///
+/// # Returns
+///
+/// The (horizontal, depth) coordinates of the final position.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// For example, the movements:
+///
+/// ```text
+/// forward 5
+/// down 5
+/// forward 8
+/// up 3
+/// down 8
+/// forward 2
+/// ```
+///
+/// Would produce a final position of (15, 60).
+///
+/// Panics if either coordinate doesn't fit in an i32 - use `record_movements_with_aim_i64` for long movement logs.
+pub fn record_movements_with_aim(input_path: &str) -> (i32, i32) {
+    let (horizontal, depth) = record_movements_with_aim_i64(input_path);
+    let to_i32 = |value: i64| {
+        i32::try_from(value).unwrap_or_else(|_| panic!("Position overflowed an i32: {}", value))
+    };
+    (to_i32(horizontal), to_i32(depth))
+}
+
+/// Record movements using aim, as described in `record_movements_with_aim`, accumulating the position as i64 values.
+///
+/// Depth grows by aim multiplied by every forward movement, so long movement logs quickly overflow an i32.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the movements
+///
+/// # Returns
+///
+/// The (horizontal, depth) coordinates of the final position.
+pub fn record_movements_with_aim_i64(input_path: &str) -> (i64, i64) {
+    let (mut horizontal, mut depth, mut aim) = (0i64, 0i64, 0i64);
+    apply_commands(read_lines(input_path), |key, score| {
+        let score = score as i64;
+        match key {
+            "forward" => {
+                depth += aim * score;
+                horizontal += score;
+            }
+            "up" => aim -= score,
+            "down" => aim += score,
+            _ => return false,
+        }
+        true
+    });
+    (horizontal, depth)
+}
+
+/// Record movements of forward, strafe, up, and down to retrieve the final (horizontal, lateral, depth) coordinates
+/// of the movements.
+///
+/// Movements work as in `record_movements`, with a third axis:
+///
+/// * forward X increases horizontal position by X units.
+/// * strafe X increases lateral position by X units.
+/// * down X increases depth by X units.
+/// * up X decreases depth by X units.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the movements
+///
+/// # Returns
+///
+/// The (horizontal, lateral, depth) coordinates of the final position.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// For example, the movements:
+///
+/// ```text
+/// forward 5
+/// strafe 3
+/// down 5
+/// forward 8
+/// strafe -1
+/// up 3
+/// down 8
+/// strafe 4
+/// forward 2
+/// ```
+///
+/// Would produce a final position of (15, 6, 10).
+///
+pub fn record_movements_3d(input_path: &str) -> (i32, i32, i32) {
+    let (mut horizontal, mut lateral, mut depth) = (0, 0, 0);
+    apply_commands(read_lines(input_path), |key, score| {
+        match key {
+            "forward" => horizontal += score,
+            "strafe" => lateral += score,
+            "up" => depth -= score,
+            "down" => depth += score,
+            _ => return false,
+        }
+        true
+    });
+    (horizontal, lateral, depth)
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+#[cfg(test)]
+mod test_record_movements {
+    use crate::record_movements;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(record_movements("inputs/example.txt"), (15, 10));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(record_movements("inputs/challenge.txt"), (1845, 916));
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to parse movement size on line 3: forward x")]
+    fn malformed_line_reported() {
+        record_movements("inputs/example_malformed.txt");
+    }
+}
+
+#[cfg(test)]
+mod test_apply_movements {
+    use crate::{apply_movements, Position};
+
+    fn lines(movements: Vec<&str>) -> impl Iterator<Item = String> + '_ {
+        movements.into_iter().map(|line| line.to_string())
+    }
+
+    #[test]
+    fn example_correct() {
+        let movements = vec![
+            "forward 5",
+            "down 5",
+            "forward 8",
+            "up 3",
+            "down 8",
+            "forward 2",
+        ];
+        assert_eq!(
+            apply_movements(lines(movements)),
+            Position {
+                horizontal: 15,
+                depth: 10
+            }
+        );
+    }
+
+    #[test]
+    fn no_movements() {
+        assert_eq!(
+            apply_movements(lines(vec![])),
+            Position {
+                horizontal: 0,
+                depth: 0
+            }
+        );
+    }
+
+    #[test]
+    fn rising_above_surface() {
+        assert_eq!(
+            apply_movements(lines(vec!["up 4", "forward 1", "down 1"])),
+            Position {
+                horizontal: 1,
+                depth: -3
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown direction on line 2: back 1")]
+    fn unknown_direction_reported() {
+        apply_movements(lines(vec!["forward 1", "back 1"]));
+    }
+}
+
+#[cfg(test)]
+mod test_record_movements_with_aim {
+    use crate::record_movements_with_aim;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(record_movements_with_aim("inputs/example.txt"), (15, 60));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            record_movements_with_aim("inputs/challenge.txt"),
+            (1845, 763408)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Position overflowed an i32: 20000000000")]
+    fn large_input_overflow_reported() {
+        record_movements_with_aim("inputs/example_large.txt");
+    }
+}
+
+#[cfg(test)]
+mod test_record_movements_with_aim_i64 {
+    use crate::{record_movements_with_aim, record_movements_with_aim_i64};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            record_movements_with_aim_i64("inputs/example.txt"),
+            (15, 60)
+        );
+    }
+
+    #[test]
+    fn question_matches_i32() {
+        let (horizontal, depth) = record_movements_with_aim("inputs/challenge.txt");
+        assert_eq!(
+            record_movements_with_aim_i64("inputs/challenge.txt"),
+            (horizontal as i64, depth as i64)
+        );
+    }
+
+    #[test]
+    fn large_input_no_overflow() {
+        // Aim of 100000 applied to two forward movements of 100000 reaches a depth far beyond an i32
+        assert_eq!(
+            record_movements_with_aim_i64("inputs/example_large.txt"),
+            (200000, 20000000000)
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_record_movements_3d {
+    use crate::record_movements_3d;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(record_movements_3d("inputs/example_3d.txt"), (15, 6, 10));
+    }
+
+    #[test]
+    fn planar_example_correct() {
+        assert_eq!(record_movements_3d("inputs/example.txt"), (15, 0, 10));
+    }
+}
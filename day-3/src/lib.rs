@@ -0,0 +1,516 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Parse the gamma and epsilon power factors from a binary power report.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the power report.
+///
+/// # Returns
+///
+/// The (gamma rate, epsilon rate) of the power report
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// For example, the power report:
+///
+/// ```text
+/// 00100
+/// 11110
+/// 10110
+/// 10111
+/// 10101
+/// 01111
+/// 00111
+/// 11100
+/// 10000
+/// 11001
+/// 00010
+/// 01010
+/// ```
+///
+/// Considering only the first bit of each number, there are five 0 bits and seven 1 bits. Since the most common bit is 1, the first bit of the gamma rate is 1.
+///
+/// The most common second bit of the numbers in the diagnostic report is 0, so the second bit of the gamma rate is 0.
+///
+/// The most common value of the third, fourth, and fifth bits are 1, 1, and 0, respectively, and so the final three bits of the gamma rate are 110.
+///
+/// So, the gamma rate is the binary number 10110, or 22 in decimal.
+///
+/// The epsilon rate is calculated in a similar way; rather than use the most common bit, the least common bit from each position is used. So, the epsilon rate is 01001, or 9 in decimal.
+///
+/// Therefore, the we Would produce a final power factors of (22, 9).
+pub fn read_power_report(input_path: &str) -> (i32, i32) {
+    let (gamma, eps) = power_report_bits(input_path);
+    // Convert byte strings to decimal
+    (
+        i32::from_str_radix(gamma.as_str(), 2).expect("Failed to parse byte string as integer"),
+        i32::from_str_radix(eps.as_str(), 2).expect("Failed to parse byte string as integer"),
+    )
+}
+
+/// Parse the gamma and epsilon power factors from a binary power report, for reports up to 64 bits wide.
+///
+/// Works as `read_power_report`, but reports wider than 31 bits don't overflow.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the power report.
+///
+/// # Returns
+///
+/// The (gamma rate, epsilon rate) of the power report
+pub fn read_power_report_u64(input_path: &str) -> (u64, u64) {
+    let (gamma, eps) = power_report_bits(input_path);
+    (
+        u64::from_str_radix(gamma.as_str(), 2).expect("Failed to parse byte string as integer"),
+        u64::from_str_radix(eps.as_str(), 2).expect("Failed to parse byte string as integer"),
+    )
+}
+
+/// The gamma and epsilon power factors of a power report, as both binary strings and decimals.
+#[derive(Debug, PartialEq)]
+pub struct PowerReport {
+    /// The most common bit at each position.
+    pub gamma: String,
+    /// The least common bit at each position.
+    pub epsilon: String,
+    /// The gamma rate in decimal.
+    pub gamma_rate: i32,
+    /// The epsilon rate in decimal.
+    pub epsilon_rate: i32,
+}
+
+/// Parse the gamma and epsilon power factors from a binary power report, keeping the binary strings they came from.
+///
+/// Works as `read_power_report`, for reports up to 31 bits wide.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the power report.
+///
+/// # Returns
+///
+/// The gamma and epsilon binary strings, and their decimal values.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Using the example report from `read_power_report`, the gamma rate is 10110 (22) and the epsilon rate is 01001 (9).
+pub fn read_power_report_with_bits(input_path: &str) -> PowerReport {
+    let (gamma, epsilon) = power_report_bits(input_path);
+    let (gamma_rate, epsilon_rate) = (
+        i32::from_str_radix(gamma.as_str(), 2).expect("Failed to parse byte string as integer"),
+        i32::from_str_radix(epsilon.as_str(), 2).expect("Failed to parse byte string as integer"),
+    );
+    PowerReport {
+        gamma,
+        epsilon,
+        gamma_rate,
+        epsilon_rate,
+    }
+}
+
+/// Count the zero and one bits at each position of the numbers in a binary power report.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the power report.
+///
+/// # Returns
+///
+/// The (zeros, ones) count for each bit position, from the most significant bit - panics if the numbers aren't all
+/// the same width.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Using the example report from `read_power_report`, the first bit is 0 five times and 1 seven times, and the
+/// second bit is 0 seven times and 1 five times, so the counts start (5, 7), (7, 5).
+pub fn bit_counts(input_path: &str) -> Vec<(usize, usize)> {
+    let reader = get_buf_reader(input_path);
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    let mut numbers_seen = 0;
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line.expect("Failed to parse line from file.");
+        // Blank lines aren't numbers, matching how the life support report reads its input
+        if line.is_empty() {
+            continue;
+        }
+        numbers_seen += 1;
+        // Every number needs the same width, otherwise bits would be compared against the wrong positions
+        if numbers_seen > 1 && line.len() != counts.len() {
+            panic!(
+                "Line {} has {} bits, expected {}: {}",
+                line_idx + 1,
+                line.len(),
+                counts.len(),
+                line
+            );
+        }
+
+        for idx in 0..line.len() {
+            let current_byte = line
+                .get(idx..idx + 1)
+                .expect("Failed to parse byte from line");
+
+            // Handle arbitrary length binary numbers in the input file, sized by the first line
+            if idx + 1 > counts.len() {
+                counts.push((0, 0));
+            }
+
+            match current_byte {
+                "0" => counts[idx].0 += 1,
+                "1" => counts[idx].1 += 1,
+                _ => panic!("Unexpected byte: {}", current_byte),
+            }
+        }
+    }
+    counts
+}
+
+/// Find the binary strings of the gamma and epsilon power factors of a binary power report.
+fn power_report_bits(input_path: &str) -> (String, String) {
+    // Convert most common bytes to gamma & epsilon, where 1 is most common on a tie
+    let mut gamma: String = String::new();
+    let mut eps: String = String::new();
+    for (zeros, ones) in bit_counts(input_path) {
+        if zeros > ones {
+            gamma.push('0');
+            eps.push('1');
+        } else {
+            gamma.push('1');
+            eps.push('0');
+        }
+    }
+    (gamma, eps)
+}
+
+#[cfg(test)]
+mod test_bit_counts {
+    use crate::bit_counts;
+
+    #[test]
+    fn example_correct() {
+        let counts = bit_counts("inputs/example.txt");
+        assert_eq!(counts.len(), 5);
+        assert_eq!(counts[0], (5, 7));
+        assert_eq!(counts[1], (7, 5));
+        assert_eq!(counts[2], (4, 8));
+    }
+
+    #[test]
+    fn blank_lines_skipped() {
+        assert_eq!(
+            bit_counts("inputs/example_blank.txt"),
+            bit_counts("inputs/example.txt")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Line 3 has 4 bits, expected 5: 1011")]
+    fn ragged_input_fails() {
+        bit_counts("inputs/example_ragged.txt");
+    }
+}
+
+#[cfg(test)]
+mod test_read_power_report {
+    use crate::{
+        read_power_report, read_power_report_u64, read_power_report_with_bits, PowerReport,
+    };
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(read_power_report("inputs/example.txt"), (22, 9));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(read_power_report("inputs/challenge.txt"), (654, 3441));
+    }
+
+    #[test]
+    fn blank_lines_skipped() {
+        assert_eq!(read_power_report("inputs/example_blank.txt"), (22, 9));
+    }
+
+    #[test]
+    #[should_panic(expected = "Line 3 has 4 bits, expected 5: 1011")]
+    fn ragged_input_fails() {
+        read_power_report("inputs/example_ragged.txt");
+    }
+
+    #[test]
+    fn example_u64_matches() {
+        assert_eq!(read_power_report_u64("inputs/example.txt"), (22, 9));
+    }
+
+    #[test]
+    fn example_bits_correct() {
+        assert_eq!(
+            read_power_report_with_bits("inputs/example.txt"),
+            PowerReport {
+                gamma: "10110".to_string(),
+                epsilon: "01001".to_string(),
+                gamma_rate: 22,
+                epsilon_rate: 9
+            }
+        );
+    }
+
+    #[test]
+    fn wide_input_u64_correct() {
+        // 40 bit wide lines, where every bit but the last is most commonly 1
+        assert_eq!(
+            read_power_report_u64("inputs/example_wide.txt"),
+            ((1 << 40) - 2, 1)
+        );
+    }
+}
+
+/// Narrow a list of binary numbers down to one, keeping only the numbers matching the bit criteria at each position.
+///
+/// If `keep_most_common` is set the most common bit is kept at each position (1 on a tie), otherwise the least common
+/// bit is kept (0 on a tie). Positions where every remaining number shares the same bit are skipped, and filtering
+/// stops once every position has been considered, so duplicated numbers narrow down to a single copy.
+fn filter_by_bit_criteria(lines: &[String], keep_most_common: bool) -> String {
+    let mut remaining = lines.to_vec();
+    let width = remaining.first().map_or(0, |line| line.len());
+    let mut idx = 0;
+    while remaining.len() > 1 && idx < width {
+        let one_count = remaining
+            .iter()
+            .filter(|line| line.as_bytes()[idx] == b'1')
+            .count();
+        let ones_most_common = one_count * 2 >= remaining.len();
+        let keep = if ones_most_common == keep_most_common {
+            b'1'
+        } else {
+            b'0'
+        };
+        // When every number shares this bit there is nothing to filter on, so keep them all
+        if one_count != 0 && one_count != remaining.len() {
+            remaining.retain(|line| line.as_bytes()[idx] == keep);
+        }
+        idx += 1;
+    }
+    remaining
+        .pop()
+        .expect("No numbers matched the bit criteria.")
+}
+
+#[cfg(test)]
+mod test_filter_by_bit_criteria {
+    use crate::{filter_by_bit_criteria, get_buf_reader};
+    use std::io::BufRead;
+
+    fn example_lines() -> Vec<String> {
+        get_buf_reader("inputs/example.txt")
+            .lines()
+            .map(|line| line.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn most_common_correct() {
+        let rating = filter_by_bit_criteria(&example_lines(), true);
+        assert_eq!(i32::from_str_radix(&rating, 2), Ok(23));
+    }
+
+    #[test]
+    fn least_common_correct() {
+        let rating = filter_by_bit_criteria(&example_lines(), false);
+        assert_eq!(i32::from_str_radix(&rating, 2), Ok(10));
+    }
+
+    #[test]
+    fn duplicated_lines_correct() {
+        let lines = vec!["10110".to_string(), "10110".to_string()];
+        assert_eq!(filter_by_bit_criteria(&lines, true), "10110");
+        assert_eq!(filter_by_bit_criteria(&lines, false), "10110");
+    }
+}
+
+/// Parse the oxygen generator and CO2 scrubber ratings from a binary diagnostic report.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the diagnostic report.
+///
+/// # Returns
+///
+/// The (oxygen generator rating, CO2 scrubber rating) of the diagnostic report
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Both the oxygen generator rating and the CO2 scrubber rating are values that can be found in your diagnostic report - finding them is the tricky part. Both values are located using a similar process that involves filtering out values until only one remains. Before searching for either rating value, start with the full list of binary numbers from your diagnostic report and consider just the first bit of those numbers. Then:
+///
+/// * Keep only numbers selected by the bit criteria for the type of rating value for which you are searching. Discard numbers which do not match the bit criteria.
+/// * If you only have one number left, stop; this is the rating value for which you are searching.
+/// * Otherwise, repeat the process, considering the next bit to the right.
+///
+/// The bit criteria depends on which type of rating value you want to find:
+///
+/// * To find oxygen generator rating, determine the most common value (0 or 1) in the current bit position, and keep only numbers with that bit in that position. If 0 and 1 are equally common, keep values with a 1 in the position being considered.
+/// * To find CO2 scrubber rating, determine the least common value (0 or 1) in the current bit position, and keep only numbers with that bit in that position. If 0 and 1 are equally common, keep values with a 0 in the position being considered.
+///
+/// For example, to determine the oxygen generator rating value using the same example diagnostic report from above:
+///
+/// * Start with all 12 numbers and consider only the first bit of each number. There are more 1 bits (7) than 0 bits (5), so keep only the 7 numbers with a 1 in the first position: 11110, 10110, 10111, 10101, 11100, 10000, and 11001.
+/// * Then, consider the second bit of the 7 remaining numbers: there are more 0 bits (4) than 1 bits (3), so keep only the 4 numbers with a 0 in the second position: 10110, 10111, 10101, and 10000.
+/// * In the third position, three of the four numbers have a 1, so keep those three: 10110, 10111, and 10101.
+/// * In the fourth position, two of the three numbers have a 1, so keep those two: 10110 and 10111.
+/// * In the fifth position, there are an equal number of 0 bits and 1 bits (one each). So, to find the oxygen generator rating, keep the number with a 1 in that position: 10111.
+/// * As there is only one number left, stop; the oxygen generator rating is 10111, or 23 in decimal.
+///
+/// Then, to determine the CO2 scrubber rating value from the same example above:
+///
+/// Start again with all 12 numbers and consider only the first bit of each number. There are fewer 0 bits (5) than 1 bits (7), so keep only the 5 numbers with a 0 in the first position: 00100, 01111, 00111, 00010, and 01010.
+/// Then, consider the second bit of the 5 remaining numbers: there are fewer 1 bits (2) than 0 bits (3), so keep only the 2 numbers with a 1 in the second position: 01111 and 01010.
+/// In the third position, there are an equal number of 0 bits and 1 bits (one each). So, to find the CO2 scrubber rating, keep the number with a 0 in that position: 01010.
+/// As there is only one number left, stop; the CO2 scrubber rating is 01010, or 10 in decimal.
+///
+/// Finally, to find the life support rating, multiply the oxygen generator rating (23) by the CO2 scrubber rating (10) to get 230.
+pub fn read_life_support_report(input_path: &str) -> (i32, i32) {
+    let (oxygen, co2) = life_support_bits(input_path);
+    (
+        i32::from_str_radix(oxygen.as_str(), 2).expect("Failed to parse byte string as integer"),
+        i32::from_str_radix(co2.as_str(), 2).expect("Failed to parse byte string as integer"),
+    )
+}
+
+/// Parse the oxygen generator and CO2 scrubber ratings from a binary diagnostic report, for reports up to 64 bits wide.
+///
+/// Works as `read_life_support_report`, but reports wider than 31 bits don't overflow.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the diagnostic report.
+///
+/// # Returns
+///
+/// The (oxygen generator rating, CO2 scrubber rating) of the diagnostic report
+pub fn read_life_support_report_u64(input_path: &str) -> (u64, u64) {
+    let (oxygen, co2) = life_support_bits(input_path);
+    (
+        u64::from_str_radix(oxygen.as_str(), 2).expect("Failed to parse byte string as integer"),
+        u64::from_str_radix(co2.as_str(), 2).expect("Failed to parse byte string as integer"),
+    )
+}
+
+/// Find the binary strings of the oxygen generator and CO2 scrubber ratings of a binary diagnostic report.
+fn life_support_bits(input_path: &str) -> (String, String) {
+    let lines = get_buf_reader(input_path)
+        .lines()
+        .map(|line| line.expect("Failed to parse line from file."))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<String>>();
+    // Every number needs the same width, otherwise bits would be compared against the wrong positions
+    if let Some(first) = lines.first() {
+        for (idx, line) in lines.iter().enumerate() {
+            if line.len() != first.len() {
+                panic!(
+                    "Line {} has {} bits, expected {}: {}",
+                    idx + 1,
+                    line.len(),
+                    first.len(),
+                    line
+                );
+            }
+        }
+    }
+    (
+        filter_by_bit_criteria(&lines, true),
+        filter_by_bit_criteria(&lines, false),
+    )
+}
+
+#[cfg(test)]
+mod test_read_life_support_report {
+    use crate::{read_life_support_report, read_life_support_report_u64};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(read_life_support_report("inputs/example.txt"), (23, 10));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            read_life_support_report("inputs/challenge.txt"),
+            (1935, 3145)
+        );
+    }
+
+    #[test]
+    fn wide_input_u64_correct() {
+        assert_eq!(
+            read_life_support_report_u64("inputs/example_wide.txt"),
+            ((1 << 40) - 1, 0)
+        );
+    }
+
+    #[test]
+    fn duplicated_input_correct() {
+        assert_eq!(
+            read_life_support_report("inputs/example_duplicate.txt"),
+            (22, 9)
+        );
+    }
+
+    #[test]
+    fn blank_lines_skipped() {
+        assert_eq!(
+            read_life_support_report("inputs/example_blank.txt"),
+            read_life_support_report("inputs/example.txt")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Line 3 has 4 bits, expected 5: 1011")]
+    fn ragged_input_fails() {
+        read_life_support_report("inputs/example_ragged.txt");
+    }
+}
@@ -0,0 +1,818 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// A square bingo board, tracking which of its tiles have been called.
+pub struct Board {
+    dim: usize,
+    slots: HashMap<String, usize>,
+    values: Vec<i32>,
+    map: Vec<bool>,
+}
+
+impl Board {
+    /// Create a square board with nothing called from its tiles, listed row by row.
+    pub fn from_tiles(tiles: &[String]) -> Board {
+        let dim = (tiles.len() as f64).sqrt() as usize;
+        if dim * dim != tiles.len() {
+            panic!("Board with {} tiles is not square", tiles.len());
+        }
+        let mut slots = HashMap::new();
+        let mut values = Vec::new();
+        for (idx, key) in tiles.iter().enumerate() {
+            slots.insert(key.clone(), idx);
+            values.push(
+                key.parse::<i32>()
+                    .unwrap_or_else(|_| panic!("Failed to parse board tile: {}", key)),
+            );
+        }
+        Board {
+            dim,
+            slots,
+            values,
+            map: vec![false; tiles.len()],
+        }
+    }
+
+    /// Mark a called tile on the board, returning True if this call gave the board its win.
+    ///
+    /// Calls that aren't on the board, or are made after the board has already won, return False.
+    pub fn mark(&mut self, call: &str) -> bool {
+        let was_won = self.is_won();
+        match self.slots.get(call) {
+            Some(idx) => {
+                self.map[*idx] = true;
+                !was_won && self.is_won()
+            }
+            None => false,
+        }
+    }
+
+    /// Return True if the board has won the game.
+    pub fn is_won(&self) -> bool {
+        self.has_win()
+    }
+
+    /// The sum of all tiles in the board that were not called.
+    pub fn unmarked_sum(&self) -> i32 {
+        self.values
+            .iter()
+            .zip(&self.map)
+            .filter(|(_, marked)| !**marked)
+            .map(|(value, _)| value)
+            .sum()
+    }
+
+    /// Return True if the board has a horizontal winning row.
+    pub fn has_horizontal(&self) -> bool {
+        // Check map[0:5], map[5:10], map[10:15], map[15:20], map[20:25]
+        for base in (0..self.map.len()).step_by(self.dim) {
+            let mut has_win = true;
+            for idx in base..base + self.dim {
+                if !self.map[idx] {
+                    has_win = false;
+                    break;
+                }
+            }
+            if has_win {
+                return has_win;
+            }
+        }
+        false
+    }
+    /// Return True if the board has a vertical winning column.
+    pub fn has_vertical(&self) -> bool {
+        // Check map[0:5:20], map[1:5:21], map[2:5:22], map[3:5:23], map[4:5:24]
+        for base in 0..self.dim {
+            let mut has_win = true;
+            for idx in (base..self.dim * self.dim).step_by(self.dim) {
+                if !self.map[idx] {
+                    has_win = false;
+                    break;
+                }
+            }
+            if has_win {
+                return has_win;
+            }
+        }
+        false
+    }
+    /// Return True if the board has a winning diagonal.
+    pub fn has_diagonal(&self) -> bool {
+        // Check map[0, 6, 12, 18, 24], map[4, 8, 12, 16, 20]
+        let mut has_win_l = true;
+        for (offset, idx) in (0..self.dim * self.dim).step_by(self.dim).enumerate() {
+            let idx = &(idx + offset);
+            if !self.map[*idx] {
+                has_win_l = false;
+                break;
+            }
+        }
+
+        let mut has_win_r = true;
+        for (offset, idx) in (self.dim - 1..self.dim * self.dim - self.dim)
+            .step_by(self.dim)
+            .enumerate()
+        {
+            let idx = &(idx - offset);
+            if !self.map[*idx] {
+                has_win_r = false;
+                break;
+            }
+        }
+
+        has_win_l || has_win_r
+    }
+    /// Return True if the board has horizontal or vertical wins - ignores diagonal wins.
+    pub fn has_win(&self) -> bool {
+        self.has_horizontal() || self.has_vertical()
+    }
+
+    /// Find the round that the board wins in, and the sum of its tiles that are still unmarked at that point.
+    ///
+    /// Rather than marking each call in turn, every tile is looked up in `call_order` - the round that each number
+    /// is first called in - so a row or column wins in the latest round of any of its tiles.
+    pub fn win_against(&self, call_order: &HashMap<i32, usize>) -> Option<(usize, i32)> {
+        let rounds = self
+            .values
+            .iter()
+            .map(|value| call_order.get(value).copied().unwrap_or(usize::MAX))
+            .collect::<Vec<usize>>();
+        let mut row_rounds = vec![0; self.dim];
+        let mut column_rounds = vec![0; self.dim];
+        for (idx, round) in rounds.iter().enumerate() {
+            row_rounds[idx / self.dim] = row_rounds[idx / self.dim].max(*round);
+            column_rounds[idx % self.dim] = column_rounds[idx % self.dim].max(*round);
+        }
+        let win_round = row_rounds
+            .into_iter()
+            .chain(column_rounds)
+            .min()
+            .filter(|round| *round != usize::MAX)?;
+        let unmarked_sum = self
+            .values
+            .iter()
+            .zip(&rounds)
+            .filter(|(_, round)| **round > win_round)
+            .map(|(value, _)| value)
+            .sum();
+        Some((win_round, unmarked_sum))
+    }
+
+    /// Render the board as a grid, with called tiles surrounded by brackets.
+    pub fn render(&self) -> String {
+        let mut tiles = vec![""; self.map.len()];
+        for (call, idx) in &self.slots {
+            tiles[*idx] = call.as_str();
+        }
+        let width = tiles.iter().map(|t| t.len()).max().unwrap_or(0);
+
+        let mut rendered = String::new();
+        for (idx, tile) in tiles.iter().enumerate() {
+            if self.map[idx] {
+                rendered.push_str(&format!("[{:>width$}]", tile, width = width));
+            } else {
+                rendered.push_str(&format!(" {:>width$} ", tile, width = width));
+            }
+            if (idx + 1) % self.dim == 0 {
+                rendered.push('\n');
+            }
+        }
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod test_board {
+    use crate::Board;
+    use std::collections::HashMap;
+
+    #[test]
+    fn unmarked_sum() {
+        let mut slots = HashMap::new();
+        let mut map = Vec::new();
+        slots.insert("1".to_string(), 0);
+        slots.insert("1234".to_string(), 1);
+        map.push(true);
+        map.push(false);
+        let values = vec![1, 1234];
+
+        let b = Board {
+            dim: 2,
+            slots,
+            values,
+            map,
+        };
+
+        assert_eq!(b.unmarked_sum(), 1234);
+    }
+
+    #[test]
+    fn unmarked_sum_repeatable() {
+        let mut slots = HashMap::new();
+        slots.insert("7".to_string(), 0);
+        slots.insert("12".to_string(), 1);
+        let values = vec![7, 12];
+        let map = vec![false, false];
+
+        let b = Board {
+            dim: 2,
+            slots,
+            values,
+            map,
+        };
+
+        assert_eq!(b.unmarked_sum(), 19);
+        assert_eq!(b.unmarked_sum(), 19);
+    }
+
+    #[test]
+    fn no_horiz_win() {
+        let slots = HashMap::new();
+        let map = vec![true, false, false, false, false, false, false, false, false];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(!b.has_horizontal());
+        assert!(!b.has_win());
+    }
+
+    #[test]
+    fn horiz_win() {
+        let slots = HashMap::new();
+        let map = vec![true, true, true, false, false, false, false, false, false];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(b.has_horizontal());
+        assert!(b.has_win());
+    }
+
+    #[test]
+    fn no_vert_win() {
+        let slots = HashMap::new();
+        let map = vec![true, false, false, false, false, false, false, false, false];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(!b.has_vertical());
+        assert!(!b.has_win());
+    }
+
+    #[test]
+    fn vert_win() {
+        let slots = HashMap::new();
+        let map = vec![true, false, false, true, false, false, true, false, false];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(b.has_vertical());
+        assert!(b.has_win());
+    }
+
+    #[test]
+    fn no_diag_win() {
+        let slots = HashMap::new();
+        let map = vec![true, false, false, false, false, false, false, false, false];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(!b.has_diagonal());
+        assert!(!b.has_win());
+    }
+
+    #[test]
+    fn diag_win_l() {
+        let slots = HashMap::new();
+        let map = vec![true, false, false, false, true, false, false, false, true];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(b.has_diagonal());
+        assert!(!b.has_win());
+    }
+
+    #[test]
+    fn diag_win_r() {
+        let slots = HashMap::new();
+        let map = vec![false, false, true, false, true, false, true, false, false];
+
+        let b = Board {
+            dim: 3,
+            slots,
+            values: vec![0; 9],
+            map,
+        };
+
+        assert!(b.has_diagonal());
+        assert!(!b.has_win());
+    }
+
+    #[test]
+    fn render_marks_called() {
+        let mut slots = HashMap::new();
+        slots.insert("1".to_string(), 0);
+        slots.insert("22".to_string(), 1);
+        slots.insert("3".to_string(), 2);
+        slots.insert("14".to_string(), 3);
+        let values = vec![1, 22, 3, 14];
+        let map = vec![true, false, false, true];
+
+        let b = Board {
+            dim: 2,
+            slots,
+            values,
+            map,
+        };
+
+        assert_eq!(b.render(), "[ 1] 22 \n  3 [14]\n");
+    }
+
+    #[test]
+    fn mark_winning_row() {
+        let tiles = ["1", "2", "3", "4", "5", "6", "7", "8", "9"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<String>>();
+        let mut b = Board::from_tiles(&tiles);
+
+        assert!(!b.mark("4"));
+        assert!(!b.mark("42"));
+        assert!(!b.mark("5"));
+        assert!(!b.is_won());
+        assert!(b.mark("6"));
+        assert!(b.is_won());
+        // Later calls are still marked, but don't win again
+        assert!(!b.mark("1"));
+        assert_eq!(b.unmarked_sum(), 2 + 3 + 7 + 8 + 9);
+    }
+
+    #[test]
+    fn win_against_matches_mark() {
+        let tiles = ["1", "2", "3", "4", "5", "6", "7", "8", "9"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<String>>();
+        let b = Board::from_tiles(&tiles);
+        // 4, 42, 5, then 6 completes the middle row, and 1 is never reached
+        let call_order = HashMap::from([(4, 0), (42, 1), (5, 2), (6, 3), (1, 4)]);
+
+        assert_eq!(b.win_against(&call_order), Some((3, 1 + 2 + 3 + 7 + 8 + 9)));
+        assert_eq!(b.win_against(&HashMap::from([(4, 0), (5, 1)])), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_square_board_fail() {
+        Board::from_tiles(&["1".to_string(), "2".to_string()]);
+    }
+}
+
+struct Solution {
+    unmarked_sum: i32,
+    rounds_to_win: usize,
+    winning_result: i32,
+}
+
+impl Solution {
+    /// The "score" of the result is defined as the sum of all unplayed tiles, times the tile that gave us the win.
+    fn score(&self) -> i32 {
+        self.unmarked_sum * self.winning_result
+    }
+
+    /// The details of how the board won.
+    fn details(&self) -> WinDetails {
+        WinDetails {
+            score: self.score(),
+            round: self.rounds_to_win,
+            winning_number: self.winning_result,
+        }
+    }
+}
+
+/// The outcome of a bingo game.
+#[derive(Debug, PartialEq)]
+pub struct BingoResult {
+    /// The score of the first board to win.
+    pub best_score: i32,
+    /// The score of the last board to win.
+    pub worst_score: i32,
+}
+
+/// Parse a bingo game as inputs and report a winning board, as well as the worst-losing board, scores.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the bingo game.
+///
+/// # Returns
+///
+/// The scores of the winning board and worst-losing board.
+///
+/// # Examples
+///
+/// ## Basic
+/// Bingo input has the format:
+///
+/// ```text
+/// 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+///
+/// 22 13 17 11  0
+///  8  2 23  4 24
+/// 21  9 14 16  7
+///  6 10  3 18  5
+///  1 12 20 15 19
+///
+///  3 15  0  2 22
+///  9 18 13 17  5
+/// 19  8  7 25 23
+/// 20 11 10 24  4
+/// 14 21 16 12  6
+///
+/// 14 21 17 24  4
+/// 10 16 15  9 19
+/// 18  8 23 26 20
+/// 22 11 13  6  5
+///  2  0 12  3  7
+/// ```
+///
+/// Where the first row indicates the order of bingo calls.
+///
+/// Returns the score of the winning board can now be calculated.
+/// The score is calculated by:
+///
+/// *  The sum of all unmarked numbers on the winning board
+/// *  Multiplied by the number that caused the board to win
+///
+/// So in this case 188 * 24 = 4512 for the best board, and 148 * 13 = 1924 for the worst
+pub fn solution(input_path: &str) -> BingoResult {
+    let (best, worst) = winning_details(input_path);
+    BingoResult {
+        best_score: best.score,
+        worst_score: worst.score,
+    }
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, BingoResult};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution("inputs/example.txt"),
+            BingoResult {
+                best_score: 4512,
+                worst_score: 1924
+            }
+        );
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            solution("inputs/challenge.txt"),
+            BingoResult {
+                best_score: 35670,
+                worst_score: 22704
+            }
+        );
+    }
+}
+
+/// Play several bingo games, and report the best winning board score and the worst-losing board score of them all.
+///
+/// # Arguments
+///
+/// * `input_paths - The input file paths each containing a bingo game.
+///
+/// # Returns
+///
+/// The highest score of any game's winning board, and the lowest score of any game's worst-losing board.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Playing the example game from `solution` alongside a game scoring 35670 and 22704, the best score is 35670 and the
+/// worst score is 1924.
+pub fn solution_multi(input_paths: &[&str]) -> BingoResult {
+    let results = input_paths
+        .iter()
+        .map(|input_path| solution(input_path))
+        .collect::<Vec<BingoResult>>();
+    BingoResult {
+        best_score: results
+            .iter()
+            .map(|r| r.best_score)
+            .max()
+            .expect("No games were played."),
+        worst_score: results
+            .iter()
+            .map(|r| r.worst_score)
+            .min()
+            .expect("No games were played."),
+    }
+}
+
+#[cfg(test)]
+mod test_solution_multi {
+    use crate::{solution, solution_multi, BingoResult};
+
+    #[test]
+    fn example_and_question_correct() {
+        assert_eq!(
+            solution_multi(&["inputs/example.txt", "inputs/challenge.txt"]),
+            BingoResult {
+                best_score: 35670,
+                worst_score: 1924
+            }
+        );
+    }
+
+    #[test]
+    fn single_game_matches_solution() {
+        assert_eq!(
+            solution_multi(&["inputs/example.txt"]),
+            solution("inputs/example.txt")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_games_fail() {
+        solution_multi(&[]);
+    }
+}
+
+/// Parse the bingo calls, in order, and every board out of an input file.
+fn read_game(input_path: &str) -> (Vec<i32>, Vec<Board>) {
+    let reader = get_buf_reader(input_path);
+    let mut lines = reader.lines().map(|l| l.unwrap());
+    let calls: Vec<i32> = lines
+        .next()
+        .expect("Failed to parse moves from input")
+        .split(",")
+        .map(|x| {
+            x.parse::<i32>()
+                .unwrap_or_else(|_| panic!("Failed to parse call: {}", x))
+        })
+        .collect();
+
+    let mut boards = Vec::new();
+    let mut board_repr = Vec::new();
+    let mut expected_size: Option<usize> = None;
+    for line in lines {
+        let entry: Vec<String> = line
+            .split(" ")
+            .filter(|x| x.trim() != "")
+            .map(|x| x.to_string())
+            .collect();
+        if entry.is_empty() {
+            continue;
+        }
+        // Set board dimensions on first iteration
+        if expected_size.is_none() {
+            expected_size = Some(entry.len() * entry.len());
+        }
+
+        board_repr.extend(entry);
+        // If we haven't met the proper dimension, keep parsing inputs
+        if board_repr.len() != expected_size.unwrap() {
+            continue;
+        }
+
+        // We've got a full board, so now we can parse into our Board struct and create a new entry
+        boards.push(Board::from_tiles(&board_repr));
+        board_repr = Vec::new();
+    }
+    (calls, boards)
+}
+
+/// The details of how a board won a bingo game.
+#[derive(Debug, PartialEq)]
+pub struct WinDetails {
+    /// The score of the board when it won.
+    pub score: i32,
+    /// The index of the call (starting from 0) that gave the board its win.
+    pub round: usize,
+    /// The number that was called to give the board its win.
+    pub winning_number: i32,
+}
+
+/// Parse a bingo game as inputs and report how the winning board, as well as the worst-losing board, won.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the bingo game.
+///
+/// # Returns
+///
+/// The win details of the winning board and worst-losing board.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Using the example game from `solution`, the winning board wins on the 12th call (round 11) of 24, and the
+/// worst-losing board wins on the 15th call (round 14) of 13.
+pub fn winning_details(input_path: &str) -> (WinDetails, WinDetails) {
+    let (calls, boards) = read_game(input_path);
+    // Index each number by the round it's first called in, so boards can be scored without replaying the calls
+    let mut call_order = HashMap::new();
+    for (round, call) in calls.iter().enumerate() {
+        call_order.entry(*call).or_insert(round);
+    }
+
+    let mut winning_scores: Vec<Solution> = Vec::new();
+    for board in boards {
+        if let Some((to_win, unmarked_sum)) = board.win_against(&call_order) {
+            winning_scores.push(Solution {
+                unmarked_sum,
+                rounds_to_win: to_win,
+                winning_result: calls[to_win],
+            });
+        }
+    }
+
+    // All boards are processed, check for the winning board
+    let mut best_idx: Option<usize> = None;
+    let mut worst_idx: Option<usize> = None;
+    for (idx, sol) in winning_scores.iter().enumerate() {
+        if best_idx.is_none_or(|b| sol.rounds_to_win < winning_scores[b].rounds_to_win) {
+            best_idx = Some(idx);
+        }
+        if worst_idx.is_none_or(|w| sol.rounds_to_win > winning_scores[w].rounds_to_win) {
+            worst_idx = Some(idx);
+        }
+    }
+    let best = winning_scores[best_idx.expect("No board won the game.")].details();
+    let worst = winning_scores[worst_idx.expect("No board won the game.")].details();
+    (best, worst)
+}
+
+#[cfg(test)]
+mod test_winning_details {
+    use crate::{winning_details, WinDetails};
+
+    #[test]
+    fn example_correct() {
+        let (best, worst) = winning_details("inputs/example.txt");
+        assert_eq!(
+            best,
+            WinDetails {
+                score: 4512,
+                round: 11,
+                winning_number: 24
+            }
+        );
+        assert_eq!(
+            worst,
+            WinDetails {
+                score: 1924,
+                round: 14,
+                winning_number: 13
+            }
+        );
+    }
+
+    #[test]
+    fn large_game_scored() {
+        // 10,000 boards of 25 distinct tiles each, drawn from 100 numbers that are called in a scrambled order
+        let mut game = (0..100)
+            .map(|i| ((i * 37) % 100).to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        for board in 0..10_000 {
+            game.push('\n');
+            for row in 0..5 {
+                let tiles = (0..5)
+                    .map(|column| format!("{:>2}", (board + (row * 5 + column) * 4) % 100))
+                    .collect::<Vec<String>>();
+                game.push_str(&format!("\n{}", tiles.join(" ")));
+            }
+        }
+        // Name the file by process so concurrent test runs don't share it
+        let input_path =
+            std::env::temp_dir().join(format!("day-4-large-game-{}.txt", std::process::id()));
+        std::fs::write(&input_path, game).expect("Failed to write large game");
+
+        let (best, worst) = winning_details(input_path.to_str().unwrap());
+        std::fs::remove_file(&input_path).ok();
+
+        assert!(best.round <= worst.round);
+    }
+}
+
+/// Parse a bingo game as inputs and report the score of the first board to win.
+///
+/// Works as `solution`, but calls are played against every board in turn and play stops as soon as any board wins,
+/// rather than scoring every board to completion.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the bingo game.
+///
+/// # Returns
+///
+/// The score of the winning board.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Using the example game from `solution`, the third board wins first on the call of 24, scoring 188 * 24 = 4512.
+pub fn first_winner(input_path: &str) -> i32 {
+    let (calls, mut boards) = read_game(input_path);
+    for call in calls {
+        let key = call.to_string();
+        // Boards are checked in file order, so the first listed board wins any tie
+        for board in boards.iter_mut() {
+            if board.mark(&key) {
+                return board.unmarked_sum() * call;
+            }
+        }
+    }
+    panic!("No board won the game.")
+}
+
+#[cfg(test)]
+mod test_first_winner {
+    use crate::{first_winner, solution};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(first_winner("inputs/example.txt"), 4512);
+    }
+
+    #[test]
+    fn question_matches_solution() {
+        assert_eq!(
+            first_winner("inputs/challenge.txt"),
+            solution("inputs/challenge.txt").best_score
+        );
+    }
+}
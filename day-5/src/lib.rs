@@ -0,0 +1,968 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// An integer coordinate on the ocean floor.
+#[derive(PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    /// The depth of the point, which is 0 for vents on a flat map.
+    pub z: usize,
+}
+/// A line of vents between two points, inclusive of both ends.
+#[derive(PartialEq, Eq, Hash)]
+pub struct Ray {
+    pub start: Point,
+    pub end: Point,
+}
+#[derive(PartialEq, Eq, Hash)]
+pub enum Direction {
+    Horizontal,
+    Vertical,
+    Diagonal,
+    /// Along the z axis, with x and y fixed.
+    Depth,
+}
+impl Ray {
+    /// Return the orientation of the ray - panics if the ray changes depth as well as x or y, since diagonal rays are
+    /// only supported on a flat map.
+    pub fn direction(&self) -> Direction {
+        if self.start.z != self.end.z {
+            if self.start.x != self.end.x || self.start.y != self.end.y {
+                panic!("Diagonal rays through depth are not supported");
+            }
+            return Direction::Depth;
+        }
+        if self.start.x == self.end.x {
+            return Direction::Vertical;
+        }
+        if self.start.y == self.end.y {
+            return Direction::Horizontal;
+        }
+        Direction::Diagonal
+    }
+
+    /// Return True if the ray is diagonal and y increases as x increases (down-right, with 0,0 in the top left).
+    pub fn is_positive_slope(&self) -> bool {
+        self.direction() == Direction::Diagonal
+            && (self.start.x < self.end.x) == (self.start.y < self.end.y)
+    }
+
+    /// Return True if the ray is diagonal and y decreases as x increases (up-right, with 0,0 in the top left).
+    pub fn is_negative_slope(&self) -> bool {
+        self.direction() == Direction::Diagonal && !self.is_positive_slope()
+    }
+
+    /// Return the leftmost (or topmost for vertical rays) end of the ray, the step between its points, and how many
+    /// points it covers.
+    fn parametric(&self) -> ((i64, i64), (i64, i64), i64) {
+        let (first, last) = if (self.start.x, self.start.y) <= (self.end.x, self.end.y) {
+            (&self.start, &self.end)
+        } else {
+            (&self.end, &self.start)
+        };
+        let origin = (first.x as i64, first.y as i64);
+        let (dx, dy) = (last.x as i64 - origin.0, last.y as i64 - origin.1);
+        let step = (dx.signum(), dy.signum());
+        let len = dx.abs().max(dy.abs()) + 1;
+        match self.direction() {
+            // A single point ray still needs a step to follow
+            Direction::Vertical => (origin, (0, 1), len),
+            _ => (origin, step, len),
+        }
+    }
+
+    /// Return the points shared between this ray and another, if any.
+    ///
+    /// Parallel rays share the overlap of their segments, and any other rays share at most the single point where
+    /// they cross - found by solving for where each ray meets the other, rather than walking their paths. Rays
+    /// through depth are compared by walking their paths instead.
+    pub fn intersection(&self, other: &Ray) -> Option<Vec<Point>> {
+        if self.direction() == Direction::Depth || other.direction() == Direction::Depth {
+            let path = self.path().into_iter().collect::<HashSet<Point>>();
+            let points = other
+                .path()
+                .into_iter()
+                .filter(|point| path.contains(point))
+                .collect::<Vec<Point>>();
+            return if points.is_empty() {
+                None
+            } else {
+                Some(points)
+            };
+        }
+        // Otherwise both rays lie flat, so they can only meet at the same depth
+        if self.start.z != other.start.z {
+            return None;
+        }
+        let ((ax, ay), (asx, asy), a_len) = self.parametric();
+        let ((bx, by), (bsx, bsy), b_len) = other.parametric();
+        let (dx, dy) = (bx - ax, by - ay);
+        let point = |t: i64| Point {
+            x: (ax + t * asx) as usize,
+            y: (ay + t * asy) as usize,
+            z: self.start.z,
+        };
+
+        let det = bsx * asy - asx * bsy;
+        let points = if det == 0 {
+            // Parallel, so the rays only meet if they lie on the same line
+            if dx * asy - dy * asx != 0 {
+                return None;
+            }
+            // Find where the other ray starts along this ray, and keep the overlapping part of both
+            let offset = if asx != 0 { dx / asx } else { dy / asy };
+            let first = offset.max(0);
+            let last = (offset + b_len - 1).min(a_len - 1);
+            (first..last + 1).map(point).collect::<Vec<Point>>()
+        } else {
+            // Solve self.origin + t * self.step == other.origin + u * other.step for whole t and u
+            let t_num = bsx * dy - bsy * dx;
+            let u_num = asx * dy - asy * dx;
+            if t_num % det != 0 || u_num % det != 0 {
+                return None;
+            }
+            let (t, u) = (t_num / det, u_num / det);
+            if t < 0 || t >= a_len || u < 0 || u >= b_len {
+                return None;
+            }
+            vec![point(t)]
+        };
+        if points.is_empty() {
+            return None;
+        }
+        Some(points)
+    }
+
+    /// Return every integer point covered by the ray, from left to right (or top to bottom for vertical rays).
+    ///
+    /// The same points are returned regardless of which end of the ray is the start.
+    pub fn path(&self) -> Vec<Point> {
+        let z = self.start.z;
+        match self.direction() {
+            Direction::Vertical => (self.start.y.min(self.end.y)..self.start.y.max(self.end.y) + 1)
+                .map(|y| Point {
+                    x: self.start.x,
+                    y,
+                    z,
+                })
+                .collect(),
+            Direction::Horizontal => (self.start.x.min(self.end.x)
+                ..self.start.x.max(self.end.x) + 1)
+                .map(|x| Point {
+                    x,
+                    y: self.start.y,
+                    z,
+                })
+                .collect(),
+            Direction::Depth => (self.start.z.min(self.end.z)..self.start.z.max(self.end.z) + 1)
+                .map(|z| Point {
+                    x: self.start.x,
+                    y: self.start.y,
+                    z,
+                })
+                .collect(),
+            Direction::Diagonal => {
+                let (left, right) = if self.start.x <= self.end.x {
+                    (&self.start, &self.end)
+                } else {
+                    (&self.end, &self.start)
+                };
+                let mut points = Vec::new();
+                let mut y = left.y as i32;
+                let off = if self.is_positive_slope() { 1 } else { -1 };
+                for x in left.x..right.x + 1 {
+                    points.push(Point {
+                        x,
+                        y: y as usize,
+                        z,
+                    });
+                    y += off;
+                }
+                points
+            }
+        }
+    }
+
+    /// Return True if the point lies on the ray - diagonal rays are not supported.
+    pub fn contains(&self, other: Point) -> bool {
+        match self.direction() {
+            Direction::Vertical => {
+                self.start.x == other.x
+                    && self.start.z == other.z
+                    && self.start.y <= other.y
+                    && other.y <= self.end.y
+            }
+            Direction::Horizontal => {
+                self.start.y == other.y
+                    && self.start.z == other.z
+                    && self.start.x <= other.x
+                    && other.x <= self.end.x
+            }
+            Direction::Depth => {
+                self.start.x == other.x
+                    && self.start.y == other.y
+                    && self.start.z <= other.z
+                    && other.z <= self.end.z
+            }
+            _ => panic!("Diagonal not supported"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_ray {
+    use crate::{Direction, Point, Ray};
+    use std::collections::HashSet;
+
+    fn ray(start: (usize, usize), end: (usize, usize)) -> Ray {
+        Ray {
+            start: Point {
+                x: start.0,
+                y: start.1,
+                z: 0,
+            },
+            end: Point {
+                x: end.0,
+                y: end.1,
+                z: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn positive_slope() {
+        for r in [
+            ray((0, 0), (8, 8)),
+            ray((8, 8), (0, 0)),
+            ray((5, 2), (7, 4)),
+        ] {
+            assert!(r.is_positive_slope());
+            assert!(!r.is_negative_slope());
+        }
+    }
+
+    #[test]
+    fn negative_slope() {
+        for r in [
+            ray((0, 8), (8, 0)),
+            ray((8, 0), (0, 8)),
+            ray((5, 5), (8, 2)),
+        ] {
+            assert!(r.is_negative_slope());
+            assert!(!r.is_positive_slope());
+        }
+    }
+
+    #[test]
+    fn straight_has_no_slope() {
+        for r in [
+            ray((0, 9), (5, 9)),
+            ray((7, 0), (7, 4)),
+            ray((3, 3), (3, 3)),
+        ] {
+            assert!(!r.is_positive_slope());
+            assert!(!r.is_negative_slope());
+        }
+    }
+
+    #[test]
+    fn depth_path() {
+        let r = Ray {
+            start: Point { x: 1, y: 2, z: 5 },
+            end: Point { x: 1, y: 2, z: 3 },
+        };
+        assert!(r.direction() == Direction::Depth);
+        let points = r
+            .path()
+            .iter()
+            .map(|p| (p.x, p.y, p.z))
+            .collect::<Vec<(usize, usize, usize)>>();
+        assert_eq!(points, vec![(1, 2, 3), (1, 2, 4), (1, 2, 5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Diagonal rays through depth are not supported")]
+    fn diagonal_depth_fail() {
+        let r = Ray {
+            start: Point { x: 0, y: 0, z: 0 },
+            end: Point { x: 2, y: 0, z: 2 },
+        };
+        r.direction();
+    }
+
+    #[test]
+    fn negative_slope_path() {
+        let points = ray((5, 5), (8, 2))
+            .path()
+            .iter()
+            .map(|p| (p.x, p.y))
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(points, vec![(5, 5), (6, 4), (7, 3), (8, 2)]);
+    }
+
+    fn path_set(r: Ray) -> HashSet<(usize, usize)> {
+        r.path().iter().map(|p| (p.x, p.y)).collect()
+    }
+
+    #[test]
+    fn diagonal_path_independent_of_order() {
+        // (start, end, expected points) with 0,0 in the top left
+        let diagonals = [
+            // Up-right
+            ((2, 6), (5, 3), [(2, 6), (3, 5), (4, 4), (5, 3)]),
+            // Up-left
+            ((5, 6), (2, 3), [(2, 3), (3, 4), (4, 5), (5, 6)]),
+            // Down-right
+            ((2, 3), (5, 6), [(2, 3), (3, 4), (4, 5), (5, 6)]),
+            // Down-left
+            ((5, 3), (2, 6), [(2, 6), (3, 5), (4, 4), (5, 3)]),
+        ];
+        for (start, end, expected) in diagonals {
+            let expected = HashSet::from(expected);
+            assert_eq!(path_set(ray(start, end)), expected);
+            assert_eq!(path_set(ray(end, start)), expected);
+        }
+    }
+
+    #[test]
+    fn straight_path_independent_of_order() {
+        assert_eq!(path_set(ray((4, 1), (4, 3))), path_set(ray((4, 3), (4, 1))));
+        assert_eq!(path_set(ray((1, 4), (3, 4))), path_set(ray((3, 4), (1, 4))));
+        assert_eq!(path_set(ray((4, 3), (4, 1))).len(), 3);
+    }
+
+    fn intersection_set(a: Ray, b: Ray) -> Option<HashSet<(usize, usize)>> {
+        a.intersection(&b)
+            .map(|points| points.iter().map(|p| (p.x, p.y)).collect())
+    }
+
+    #[test]
+    fn crossing_intersection() {
+        assert_eq!(
+            intersection_set(ray((7, 0), (7, 4)), ray((9, 4), (3, 4))),
+            Some(HashSet::from([(7, 4)]))
+        );
+        assert_eq!(
+            intersection_set(ray((0, 0), (8, 8)), ray((8, 0), (0, 8))),
+            Some(HashSet::from([(4, 4)]))
+        );
+        assert_eq!(
+            intersection_set(ray((8, 0), (0, 8)), ray((5, 9), (5, 0))),
+            Some(HashSet::from([(5, 3)]))
+        );
+        assert_eq!(
+            intersection_set(ray((7, 0), (7, 3)), ray((9, 4), (3, 4))),
+            None
+        );
+    }
+
+    #[test]
+    fn diagonals_crossing_between_points() {
+        // These cross at 0.5,0.5, which isn't a whole point
+        assert_eq!(
+            intersection_set(ray((0, 0), (1, 1)), ray((0, 1), (1, 0))),
+            None
+        );
+    }
+
+    #[test]
+    fn overlapping_intersection() {
+        assert_eq!(
+            intersection_set(ray((0, 9), (5, 9)), ray((2, 9), (0, 9))),
+            Some(HashSet::from([(0, 9), (1, 9), (2, 9)]))
+        );
+        assert_eq!(
+            intersection_set(ray((0, 0), (4, 4)), ray((6, 6), (3, 3))),
+            Some(HashSet::from([(3, 3), (4, 4)]))
+        );
+        assert_eq!(
+            intersection_set(ray((5, 0), (5, 3)), ray((5, 3), (5, 6))),
+            Some(HashSet::from([(5, 3)]))
+        );
+        assert_eq!(
+            intersection_set(ray((0, 0), (2, 2)), ray((3, 3), (5, 5))),
+            None
+        );
+        assert_eq!(
+            intersection_set(ray((0, 0), (2, 2)), ray((0, 1), (2, 3))),
+            None
+        );
+    }
+}
+
+/// Parse the rays out of an input file, ordering each ray's points so that the start is before the end.
+///
+/// Points are either `x,y` or `x,y,z`, where a missing z is taken to be 0.
+fn parse_rays(input_path: &str, ignore_diagonal: bool) -> Vec<Ray> {
+    let reader = get_buf_reader(input_path);
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(line_idx, line)| {
+            let line_number = line_idx + 1;
+            let line =
+                line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_number, e));
+            if line.trim().is_empty() {
+                return None;
+            }
+            let parse_point = |point: &str| {
+                let coords = point
+                    .split(',')
+                    .map(|x| {
+                        x.trim().parse::<usize>().unwrap_or_else(|_| {
+                            panic!("Failed to parse line {}: {}", line_number, line)
+                        })
+                    })
+                    .collect::<Vec<usize>>();
+                match coords[..] {
+                    [x, y] => Point { x, y, z: 0 },
+                    [x, y, z] => Point { x, y, z },
+                    _ => panic!("Failed to parse line {}: {}", line_number, line),
+                }
+            };
+            let (start, end) = line
+                .split_once(" -> ")
+                .unwrap_or_else(|| panic!("Failed to parse line {}: {}", line_number, line));
+            let (start, end) = (parse_point(start), parse_point(end));
+            if (start.x, start.y, start.z) > (end.x, end.y, end.z) {
+                return Some(Ray {
+                    start: end,
+                    end: start,
+                });
+            }
+            Some(Ray { start, end })
+        })
+        .filter(|ray| {
+            if ignore_diagonal {
+                ray.direction() != Direction::Diagonal
+            } else {
+                true
+            }
+        })
+        .collect()
+}
+
+/// Find the largest coordinates that any of a set of rays reach.
+///
+/// # Arguments
+///
+/// * `rays` - The rays to bound.
+///
+/// # Returns
+///
+/// The inclusive maximum x and y coordinates of the rays, or 0,0 if there are no rays - depth is not bounded.
+pub fn bounds(rays: &[Ray]) -> (usize, usize) {
+    rays.iter()
+        .flat_map(|ray| [&ray.start, &ray.end])
+        .fold((0, 0), |(x, y), point| (x.max(point.x), y.max(point.y)))
+}
+
+#[cfg(test)]
+mod test_bounds {
+    use crate::{bounds, Point, Ray};
+
+    fn ray(start: (usize, usize), end: (usize, usize)) -> Ray {
+        Ray {
+            start: Point {
+                x: start.0,
+                y: start.1,
+                z: 0,
+            },
+            end: Point {
+                x: end.0,
+                y: end.1,
+                z: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn bounds_correct() {
+        assert_eq!(bounds(&[ray((0, 9), (5, 9)), ray((8, 0), (0, 8))]), (8, 9));
+        assert_eq!(bounds(&[ray((3, 4), (1, 4))]), (3, 4));
+    }
+
+    #[test]
+    fn empty_bounds_correct() {
+        assert_eq!(bounds(&[]), (0, 0));
+    }
+}
+
+/// Count the points of an input file covered by at least two rays, by intersecting every pair of rays.
+///
+/// This agrees with `solution` (without deduplicating rays) at its default minimum overlap, but never walks the
+/// path of each ray.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the rays.
+/// * `ignore_diagonal` - Only count horizontal and vertical rays.
+///
+/// # Returns
+///
+/// The number of distinct points shared by at least two rays.
+pub fn count_intersections(input_path: &str, ignore_diagonal: bool) -> usize {
+    let rays = parse_rays(input_path, ignore_diagonal);
+    let mut shared = HashSet::new();
+    for (idx, ray) in rays.iter().enumerate() {
+        for other in &rays[idx + 1..] {
+            if let Some(points) = ray.intersection(other) {
+                shared.extend(points);
+            }
+        }
+    }
+    shared.len()
+}
+
+#[cfg(test)]
+mod test_count_intersections {
+    use crate::{count_intersections, solution, DEFAULT_MAX_DIM, DEFAULT_MIN_OVERLAP};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(count_intersections("inputs/example.txt", true), 5);
+        assert_eq!(count_intersections("inputs/example.txt", false), 12);
+    }
+
+    #[test]
+    fn example_3d_correct() {
+        // The two depth rays share 1,1,2 to 1,1,4, and the last two rays cross at 2,0,3
+        assert_eq!(count_intersections("inputs/example_3d.txt", true), 4);
+    }
+
+    #[test]
+    fn question_matches_solution() {
+        for ignore_diagonal in [true, false] {
+            assert_eq!(
+                count_intersections("inputs/challenge.txt", ignore_diagonal),
+                solution(
+                    "inputs/challenge.txt",
+                    ignore_diagonal,
+                    DEFAULT_MIN_OVERLAP,
+                    false,
+                    DEFAULT_MAX_DIM
+                )
+            );
+        }
+    }
+}
+
+/// Count the number of rays covering each point that any ray passes through.
+fn count_overlaps(rays: Vec<Ray>) -> HashMap<Point, i32> {
+    let mut overlaps: HashMap<Point, i32> = HashMap::new();
+    for ray in rays {
+        for point in ray.path() {
+            let mut val = 0;
+            if overlaps.contains_key(&point) {
+                val = *overlaps.get(&point).unwrap();
+            }
+            val += 1;
+            overlaps.insert(point, val);
+        }
+    }
+    overlaps
+}
+
+/// Count the number of rays covering each point of an input file that any ray passes through.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the rays.
+/// * `ignore_diagonal` - Only count horizontal and vertical rays.
+///
+/// # Returns
+///
+/// The number of rays covering each point - points no ray covers are left out.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Using the example input from `solution`, the horizontal and vertical rays cover 7,4 twice (`9,4 -> 3,4` and
+/// `7,0 -> 7,4`), and 0,9 twice (`0,9 -> 5,9` and `0,9 -> 2,9`).
+pub fn overlap_map(input_path: &str, ignore_diagonal: bool) -> HashMap<Point, i32> {
+    count_overlaps(parse_rays(input_path, ignore_diagonal))
+}
+
+#[cfg(test)]
+mod test_overlap_map {
+    use crate::{overlap_map, Point};
+
+    #[test]
+    fn example_coverage_correct() {
+        let overlaps = overlap_map("inputs/example.txt", true);
+        assert_eq!(overlaps.get(&Point { x: 7, y: 4, z: 0 }), Some(&2));
+        assert_eq!(overlaps.get(&Point { x: 2, y: 1, z: 0 }), Some(&1));
+        assert_eq!(overlaps.get(&Point { x: 5, y: 5, z: 0 }), None);
+        assert_eq!(overlaps.len(), 21);
+    }
+
+    #[test]
+    fn example_diagonal_coverage_correct() {
+        // 0,0 -> 8,8, 8,0 -> 0,8 and 9,4 -> 3,4 all cross 4,4
+        let overlaps = overlap_map("inputs/example.txt", false);
+        assert_eq!(overlaps.get(&Point { x: 4, y: 4, z: 0 }), Some(&3));
+    }
+}
+
+/// The number of rays that must cover a point for it to be dangerous.
+pub const DEFAULT_MIN_OVERLAP: i32 = 2;
+/// The largest width or height of the ocean floor that will be mapped.
+pub const DEFAULT_MAX_DIM: usize = 10_000;
+
+/// TODO
+///
+/// # Arguments
+///
+/// * `input_path - The input file path TODO
+/// * `ignore_diagonal` - Only count horizontal and vertical rays.
+/// * `min_overlap` - The number of rays that must cover a point for it to be counted, normally `DEFAULT_MIN_OVERLAP`.
+/// * `dedup_rays` - Only count identical rays (including the same points in reverse order) once.
+/// * `max_dim` - The largest width or height of the map, normally `DEFAULT_MAX_DIM` - panics if the rays reach
+///   further.
+///
+/// # Returns
+///
+/// TODO
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// You come across a field of hydrothermal vents on the ocean floor!
+/// These vents constantly produce large, opaque clouds, so it would be best to avoid them if possible.
+///
+/// They tend to form in lines; the submarine helpfully produces a list of nearby lines of vents (your puzzle input)
+/// for you to review. For example:
+///
+/// ```text
+/// 0,9 -> 5,9
+/// 8,0 -> 0,8
+/// 9,4 -> 3,4
+/// 2,2 -> 2,1
+/// 7,0 -> 7,4
+/// 6,4 -> 2,0
+/// 0,9 -> 2,9
+/// 3,4 -> 1,4
+/// 0,0 -> 8,8
+/// 5,5 -> 8,2
+/// ```
+///
+/// Each line of vents is given as a line segment in the format x1,y1 -> x2,y2 where x1,y1 are the coordinates
+/// of one end the line segment and x2,y2 are the coordinates of the other end. These line segments include the
+/// Bapoints at both ends. In other words:
+///
+/// * An entry like 1,1 -> 1,3 covers points 1,1, 1,2, and 1,3.
+/// * An entry like 9,7 -> 7,7 covers points 9,7, 8,7, and 7,7.
+///
+/// For now, only consider horizontal and vertical lines: lines where either x1 = x2 or y1 = y2.
+///
+/// So, the horizontal and vertical lines from the above list would produce the following diagram:
+///
+/// ```text
+/// .......1..
+/// ..1....1..
+/// ..1....1..
+/// .......1..
+/// .112111211
+/// ..........
+/// ..........
+/// ..........
+/// ..........
+/// 222111....
+/// ```
+///
+/// In this diagram, the top left corner is 0,0 and the bottom right corner is 9,9.
+/// Each position is shown as the number of lines which cover that point or . if no line covers that point.
+/// The top-left pair of 1s, for example, comes from 2,2 -> 2,1; the very bottom row is formed by the overlapping
+/// lines 0,9 -> 5,9 and 0,9 -> 2,9.
+///
+/// To avoid the most dangerous areas, you need to determine the number of points where at least two lines overlap.
+/// In the above example, this is anywhere in the diagram with a 2 or larger - a total of 5 points.
+///
+/// Consider only horizontal and vertical lines. At how many points do at least two lines overlap?
+pub fn solution(
+    input_path: &str,
+    ignore_diagonal: bool,
+    min_overlap: i32,
+    dedup_rays: bool,
+    max_dim: usize,
+) -> usize {
+    let mut rays = parse_rays(input_path, ignore_diagonal);
+    let (max_x, max_y) = bounds(&rays);
+    if max_x >= max_dim || max_y >= max_dim {
+        panic!(
+            "Map of {}x{} is larger than the maximum of {}x{}",
+            max_x + 1,
+            max_y + 1,
+            max_dim,
+            max_dim
+        );
+    }
+    if dedup_rays {
+        // Rays are normalized so their start is before their end, so reversed duplicates are caught here as well
+        rays = rays
+            .into_iter()
+            .collect::<HashSet<Ray>>()
+            .into_iter()
+            .collect();
+    }
+    count_overlaps(rays)
+        .values()
+        .filter(|x| **x >= min_overlap)
+        .count()
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, DEFAULT_MAX_DIM, DEFAULT_MIN_OVERLAP};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution(
+                "inputs/example.txt",
+                true,
+                DEFAULT_MIN_OVERLAP,
+                false,
+                DEFAULT_MAX_DIM
+            ),
+            5
+        );
+        assert_eq!(
+            solution(
+                "inputs/example.txt",
+                false,
+                DEFAULT_MIN_OVERLAP,
+                false,
+                DEFAULT_MAX_DIM
+            ),
+            12
+        );
+    }
+
+    #[test]
+    fn example_min_overlap_correct() {
+        assert_eq!(
+            solution("inputs/example.txt", true, 3, false, DEFAULT_MAX_DIM),
+            0
+        );
+        assert_eq!(
+            solution("inputs/example.txt", false, 3, false, DEFAULT_MAX_DIM),
+            2
+        );
+    }
+
+    #[test]
+    fn example_3d_correct() {
+        assert_eq!(
+            solution(
+                "inputs/example_3d.txt",
+                true,
+                DEFAULT_MIN_OVERLAP,
+                false,
+                DEFAULT_MAX_DIM
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn duplicate_rays_deduped() {
+        let path = "inputs/example_duplicate.txt";
+        assert_eq!(
+            solution(path, true, DEFAULT_MIN_OVERLAP, false, DEFAULT_MAX_DIM),
+            6
+        );
+        assert_eq!(
+            solution(path, true, DEFAULT_MIN_OVERLAP, true, DEFAULT_MAX_DIM),
+            0
+        );
+    }
+
+    #[test]
+    fn oversized_map_allowed_under_cap() {
+        assert_eq!(
+            solution("inputs/example_oversized.txt", false, 1, false, 20_001),
+            20_006
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn oversized_map_fail() {
+        solution(
+            "inputs/example_oversized.txt",
+            false,
+            1,
+            false,
+            DEFAULT_MAX_DIM,
+        );
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            solution(
+                "inputs/challenge.txt",
+                true,
+                DEFAULT_MIN_OVERLAP,
+                false,
+                DEFAULT_MAX_DIM
+            ),
+            8111
+        );
+        assert_eq!(
+            solution(
+                "inputs/challenge.txt",
+                false,
+                DEFAULT_MIN_OVERLAP,
+                false,
+                DEFAULT_MAX_DIM
+            ),
+            22088
+        );
+    }
+}
+
+/// The largest width or height of a map that will be rendered.
+const MAX_RENDER_DIM: usize = 100;
+
+/// Render the overlap map of an input file as ASCII.
+///
+/// Each point is shown as the number of rays covering it (capped at 9), or `.` if no ray covers it.
+///
+/// # Arguments
+///
+/// * `input_path` - The input file path containing the rays.
+/// * `ignore_diagonal` - Only render horizontal and vertical rays.
+///
+/// # Returns
+///
+/// The rendered map of the points at depth 0, with the top left corner being 0,0 - or None if the map is larger than
+/// 100x100.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The horizontal and vertical rays of the example input render as:
+///
+/// ```text
+/// .......1..
+/// ..1....1..
+/// ..1....1..
+/// .......1..
+/// .112111211
+/// ..........
+/// ..........
+/// ..........
+/// ..........
+/// 222111....
+/// ```
+pub fn render(input_path: &str, ignore_diagonal: bool) -> Option<String> {
+    let rays = parse_rays(input_path, ignore_diagonal);
+    let (max_x, max_y) = bounds(&rays);
+    let (width, height) = (max_x + 1, max_y + 1);
+    if width > MAX_RENDER_DIM || height > MAX_RENDER_DIM {
+        return None;
+    }
+    let overlaps = count_overlaps(rays);
+
+    let mut rendered = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            match overlaps.get(&Point { x, y, z: 0 }) {
+                Some(count) => rendered.push_str(&count.min(&9).to_string()),
+                None => rendered.push('.'),
+            }
+        }
+        rendered.push('\n');
+    }
+    Some(rendered)
+}
+
+#[cfg(test)]
+mod test_render {
+    use crate::render;
+
+    #[test]
+    fn example_correct() {
+        let expected = [
+            ".......1..",
+            "..1....1..",
+            "..1....1..",
+            ".......1..",
+            ".112111211",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            "222111....",
+        ];
+        assert_eq!(
+            render("inputs/example.txt", true),
+            Some(expected.join("\n") + "\n")
+        );
+    }
+
+    #[test]
+    fn example_with_diagonals_correct() {
+        let expected = [
+            "1.1....11.",
+            ".111...2..",
+            "..2.1.111.",
+            "...1.2.2..",
+            ".112313211",
+            "...1.2....",
+            "..1...1...",
+            ".1.....1..",
+            "1.......1.",
+            "222111....",
+        ];
+        assert_eq!(
+            render("inputs/example.txt", false),
+            Some(expected.join("\n") + "\n")
+        );
+    }
+
+    #[test]
+    fn large_input_skipped() {
+        assert_eq!(render("inputs/challenge.txt", true), None);
+    }
+}
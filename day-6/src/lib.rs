@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// The default timer of a newly created lanternfish.
+pub const NEW_FISH_TTR: usize = 8;
+/// The default timer a lanternfish resets to after reproducing.
+pub const OLD_FISH_TTR: usize = 6;
+
+/// Errors encountered while reading the ages of a lanternfish population.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// An age wasn't a number.
+    InvalidAge(String),
+    /// An age was outside of the timers a lanternfish can have, 0 to the timer of a newly created lanternfish.
+    AgeOutOfRange { age: usize, max: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidAge(token) => write!(f, "Invalid lanternfish age: {:?}", token),
+            ParseError::AgeOutOfRange { age, max } => {
+                write!(f, "Lanternfish age {} is outside of 0 to {}", age, max)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Return the number of lanternfish alive after X days given an initial population.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial lanternfish ages.
+/// * `days` - The number of days to count lanternfish over.
+/// * `new_fish_ttr` - The timer of a newly created lanternfish, normally `NEW_FISH_TTR`.
+/// * `old_fish_ttr` - The timer a lanternfish resets to after reproducing, normally `OLD_FISH_TTR`.
+///
+/// # Returns
+///
+/// The number of lanternfish after the given duration, or an error if any initial age is malformed.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Rules for lanternfish growth are as follows:
+///
+/// * Each lanternfish creates a new lanternfish once every 7 days.
+/// * New lanternfish require two additional days for their first cycle (9 days).
+///
+/// So, suppose you have a lanternfish with an internal timer value of 3:
+///
+/// * After one day, its internal timer would become 2.
+/// * After another day, its internal timer would become 1.
+/// * After another day, its internal timer would become 0.
+/// * After another day, its internal timer would reset to 6, and it would create a new lanternfish with an internal timer of 8.
+/// * After another day, the first lanternfish would have an internal timer of 5, and the second lanternfish would have an internal timer of 7.
+///
+/// So, given initial ages of 3,4,3,1,2 - in 80 days, the population would be 5934.
+pub fn solution(
+    input_path: &str,
+    days: usize,
+    new_fish_ttr: usize,
+    old_fish_ttr: usize,
+) -> Result<usize, ParseError> {
+    Ok(simulate(input_path, days, new_fish_ttr, old_fish_ttr)?
+        .values()
+        .sum())
+}
+
+/// Read the internal timer of each lanternfish in the initial population, returning the first malformed age.
+///
+/// Ages can be at most `new_fish_ttr`, the timer of a newly created lanternfish.
+fn read_population(input_path: &str, new_fish_ttr: usize) -> Result<Vec<usize>, ParseError> {
+    let reader = get_buf_reader(input_path);
+    let mut population = Vec::new();
+    for line in reader.lines() {
+        for token in line.expect("Failed to read line from file").split(",") {
+            let age = token
+                .parse::<usize>()
+                .map_err(|_| ParseError::InvalidAge(token.to_string()))?;
+            if age > new_fish_ttr {
+                return Err(ParseError::AgeOutOfRange {
+                    age,
+                    max: new_fish_ttr,
+                });
+            }
+            population.push(age);
+        }
+    }
+    Ok(population)
+}
+
+/// Simulate lanternfish growth over X days, returning the population keyed by internal timer, or an error if any
+/// initial age is malformed.
+fn simulate(
+    input_path: &str,
+    days: usize,
+    new_fish_ttr: usize,
+    old_fish_ttr: usize,
+) -> Result<HashMap<usize, usize>, ParseError> {
+    let population = read_population(input_path, new_fish_ttr)?;
+
+    fn add_key<K, V>(hash_map: &mut HashMap<K, V>, key: K, value: V)
+    where
+        V: std::ops::Add<Output = V>,
+        V: std::ops::AddAssign,
+        K: Eq,
+        K: PartialEq,
+        K: std::hash::Hash,
+        V: Copy,
+    {
+        let _ = *hash_map
+            .entry(key)
+            .and_modify(|v| *v += value)
+            .or_insert(value);
+    }
+
+    let mut pop_by_time: HashMap<usize, usize> = HashMap::new();
+    for fish_ttr in population {
+        add_key(&mut pop_by_time, fish_ttr, 1);
+    }
+
+    for _ in 0..days {
+        let mut new_pop: HashMap<usize, usize> = HashMap::new();
+        for (ttr, current) in pop_by_time {
+            if ttr == 0 {
+                // Each fish at ttr 0 reproduces - create this many NEW_FISH
+                add_key(&mut new_pop, new_fish_ttr, current);
+                // Each fish at this new TTR ages out into an OLD_FISH timer
+                add_key(&mut new_pop, old_fish_ttr, current);
+                continue;
+            }
+            // Otherwise, age this population
+            add_key(&mut new_pop, ttr - 1, current);
+        }
+
+        pop_by_time = new_pop;
+    }
+    Ok(pop_by_time)
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, ParseError, NEW_FISH_TTR, OLD_FISH_TTR};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution("inputs/example.txt", 80, NEW_FISH_TTR, OLD_FISH_TTR),
+            Ok(5934)
+        );
+    }
+
+    #[test]
+    fn example_custom_timers_correct() {
+        // With fish resetting to 0, each fish reproduces every day once its timer first runs out, and new fish start
+        // at 4 (the oldest initial age, as initial ages can't be above the new fish timer)
+        assert_eq!(solution("inputs/example.txt", 5, 4, 0), Ok(17));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            solution("inputs/challenge.txt", 80, NEW_FISH_TTR, OLD_FISH_TTR),
+            Ok(365862)
+        );
+    }
+
+    #[test]
+    fn invalid_age_fail() {
+        assert_eq!(
+            solution("inputs/example_bad_age.txt", 80, NEW_FISH_TTR, OLD_FISH_TTR),
+            Err(ParseError::InvalidAge("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn out_of_range_age_fail() {
+        assert_eq!(
+            solution("inputs/example_old_age.txt", 80, NEW_FISH_TTR, OLD_FISH_TTR),
+            Err(ParseError::AgeOutOfRange { age: 9, max: 8 })
+        );
+    }
+
+    #[test]
+    fn custom_timer_ages_ok() {
+        // The fish aged 3 reproduces on day 4, so 4 fish aged 5, 4, 5, and 9 remain after day 5
+        assert_eq!(solution("inputs/example_custom_ttr.txt", 5, 10, 6), Ok(4));
+        assert_eq!(
+            solution("inputs/example_custom_ttr.txt", 5, 9, 6),
+            Err(ParseError::AgeOutOfRange { age: 10, max: 9 })
+        );
+    }
+}
+
+/// Return the number of lanternfish at each internal timer value after X days, using the default timers.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial lanternfish ages.
+/// * `days` - The number of days to count lanternfish over.
+///
+/// # Returns
+///
+/// The number of lanternfish with each internal timer value, indexed by timer, or an error if any initial age is
+/// malformed.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Given initial ages of 3,4,3,1,2 - after 18 days the population is:
+///
+/// ```text
+/// 6,0,6,4,5,6,0,1,1,2,6,0,1,1,1,2,2,3,3,4,6,7,8,8,8,8
+/// ```
+///
+/// So the distribution would be [3, 5, 3, 2, 2, 1, 5, 1, 4].
+pub fn final_distribution(
+    input_path: &str,
+    days: usize,
+) -> Result<[usize; NEW_FISH_TTR + 1], ParseError> {
+    let mut distribution = [0; NEW_FISH_TTR + 1];
+    for (ttr, count) in simulate(input_path, days, NEW_FISH_TTR, OLD_FISH_TTR)? {
+        distribution[ttr] += count;
+    }
+    Ok(distribution)
+}
+
+#[cfg(test)]
+mod test_final_distribution {
+    use crate::final_distribution;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            final_distribution("inputs/example.txt", 18),
+            Ok([3, 5, 3, 2, 2, 1, 5, 1, 4])
+        );
+    }
+
+    #[test]
+    fn example_sums_to_solution() {
+        assert_eq!(
+            final_distribution("inputs/example.txt", 80)
+                .unwrap()
+                .iter()
+                .sum::<usize>(),
+            5934
+        );
+    }
+}
+
+/// The number of distinct internal timer values, using the default timers.
+const NUM_TIMERS: usize = NEW_FISH_TTR + 1;
+
+/// A transition between lanternfish populations, where `m[to][from]` fish are produced per fish at timer `from`.
+type Transition = [[u128; NUM_TIMERS]; NUM_TIMERS];
+
+/// Multiply two transitions together - panics if any count overflows.
+fn multiply(a: &Transition, b: &Transition) -> Transition {
+    let mut product = [[0; NUM_TIMERS]; NUM_TIMERS];
+    for (row, a_row) in product.iter_mut().zip(a) {
+        for (col, cell) in row.iter_mut().enumerate() {
+            for (k, a_value) in a_row.iter().enumerate() {
+                *cell = a_value
+                    .checked_mul(b[k][col])
+                    .and_then(|v| v.checked_add(*cell))
+                    .expect("Lanternfish population overflowed.");
+            }
+        }
+    }
+    product
+}
+
+/// Return the number of lanternfish alive after X days using the default timers, in O(log X) steps.
+///
+/// Each day is the same linear transition of the population by timer, so X days is that transition raised to the
+/// power X, found by repeated squaring.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing initial lanternfish ages.
+/// * `days` - The number of days to count lanternfish over.
+///
+/// # Returns
+///
+/// The number of lanternfish after the given duration, or an error if any initial age is malformed - panics if the
+/// population doesn't fit in a u128.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Given initial ages of 3,4,3,1,2 - in 256 days, the population would be 26984457539, the same as `solution`.
+pub fn solution_matrix(input_path: &str, days: usize) -> Result<u128, ParseError> {
+    let mut step: Transition = [[0; NUM_TIMERS]; NUM_TIMERS];
+    for ttr in 1..NUM_TIMERS {
+        // Every other fish ages by a day
+        step[ttr - 1][ttr] = 1;
+    }
+    // Fish at timer 0 reset, and create a new fish
+    step[OLD_FISH_TTR][0] += 1;
+    step[NEW_FISH_TTR][0] += 1;
+
+    let mut total: Transition = [[0; NUM_TIMERS]; NUM_TIMERS];
+    for (ttr, row) in total.iter_mut().enumerate() {
+        row[ttr] = 1;
+    }
+    let mut remaining = days;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            total = multiply(&total, &step);
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            step = multiply(&step, &step);
+        }
+    }
+
+    let mut counts = [0u128; NUM_TIMERS];
+    for ttr in read_population(input_path, NEW_FISH_TTR)? {
+        counts[ttr] += 1;
+    }
+    let population = total
+        .iter()
+        .flat_map(|row| row.iter().zip(&counts))
+        .try_fold(0u128, |sum, (m, c)| m.checked_mul(*c)?.checked_add(sum))
+        .expect("Lanternfish population overflowed.");
+    Ok(population)
+}
+
+#[cfg(test)]
+mod test_solution_matrix {
+    use crate::{solution, solution_matrix, NEW_FISH_TTR, OLD_FISH_TTR};
+
+    #[test]
+    fn example_matches_solution() {
+        for days in [0, 1, 18, 80, 256] {
+            assert_eq!(
+                solution_matrix("inputs/example.txt", days),
+                solution("inputs/example.txt", days, NEW_FISH_TTR, OLD_FISH_TTR).map(|v| v as u128)
+            );
+        }
+    }
+
+    #[test]
+    fn question_matches_solution() {
+        for days in [80, 256] {
+            assert_eq!(
+                solution_matrix("inputs/challenge.txt", days),
+                solution("inputs/challenge.txt", days, NEW_FISH_TTR, OLD_FISH_TTR)
+                    .map(|v| v as u128)
+            );
+        }
+    }
+
+    #[test]
+    fn large_day_count_correct() {
+        // Far past where iterating would overflow a usize
+        assert!(solution_matrix("inputs/example.txt", 700).unwrap() > usize::MAX as u128);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lanternfish population overflowed.")]
+    fn population_overflow_fails() {
+        // Every transition still fits in a u128 after 999 days, but the example population doesn't
+        assert!(solution_matrix("inputs/example.txt", 998).is_ok());
+        let _ = solution_matrix("inputs/example.txt", 999);
+    }
+}
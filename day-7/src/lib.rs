@@ -0,0 +1,240 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Read a list of positions from an input file, separated by any mix of commas, spaces, and newlines.
+fn read_positions(input_path: &str) -> Vec<i32> {
+    let reader = get_buf_reader(input_path);
+    reader
+        .lines()
+        .flat_map(|line| {
+            line.expect("Failed to read line from file")
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse::<i32>().expect("Failed to parse value from file."))
+                .collect::<Vec<i32>>()
+        })
+        .collect()
+}
+
+/// Return the total cost of moving every position to the target, where moving N steps costs 1 + 2 + ... + N.
+fn triangular_cost(positions: &[i32], target: i32) -> i64 {
+    positions
+        .iter()
+        .map(|v| (v - target).abs() as i64)
+        // Cost is the sum of the distance values - e.g. distance 3 cost is 3+2+1 (n * (n+1) / 2)
+        .map(|n| n * (n + 1) / 2)
+        .sum()
+}
+
+/// Return the total cost of moving every position to the target, where moving N steps costs N.
+fn linear_cost(positions: &[i32], target: i32) -> i64 {
+    positions.iter().map(|v| (v - target).abs() as i64).sum()
+}
+
+/// Calculate the total cost of aligning a set of numbers at every value between the smallest and largest number.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing integers to align.
+/// * `triangular - Whether moving N steps costs 1 + 2 + ... + N, rather than N.
+///
+/// # Returns
+///
+/// Each candidate value from the smallest to the largest number inclusive, paired with the total cost of aligning
+/// every number at it.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// Given the numbers 16,1,2,0,4,2,7,1,2,14 with linear costs, the curve starts at (0, 49), falls to (2, 37), and
+/// climbs to (16, 111).
+pub fn cost_curve(input_path: &str, triangular: bool) -> Vec<(i32, i64)> {
+    let to_align = read_positions(input_path);
+    let smallest_val = *to_align
+        .iter()
+        .min()
+        .expect("Failed to parse population data");
+    let largest_val = *to_align
+        .iter()
+        .max()
+        .expect("Failed to parse population data");
+    (smallest_val..=largest_val)
+        .map(|val| {
+            let cost = if triangular {
+                triangular_cost(&to_align, val)
+            } else {
+                linear_cost(&to_align, val)
+            };
+            (val, cost)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_cost_curve {
+    use crate::cost_curve;
+
+    #[test]
+    fn example_linear_correct() {
+        let curve = cost_curve("inputs/example.txt", false);
+        assert_eq!(curve.len(), 17);
+        assert_eq!(curve[0], (0, 49));
+        assert_eq!(curve[16], (16, 111));
+        assert_eq!(curve.iter().min_by_key(|(_, cost)| *cost), Some(&(2, 37)));
+    }
+
+    #[test]
+    fn separators_interchangeable() {
+        let curve = cost_curve("inputs/example_lines.txt", false);
+        assert_eq!(curve.iter().min_by_key(|(_, cost)| *cost), Some(&(2, 37)));
+        assert_eq!(curve, cost_curve("inputs/example.txt", false));
+    }
+
+    #[test]
+    fn example_triangular_minimum() {
+        let curve = cost_curve("inputs/example.txt", true);
+        assert_eq!(curve.iter().min_by_key(|(_, cost)| *cost), Some(&(5, 168)));
+    }
+
+    #[test]
+    fn question_triangular_minimum() {
+        let curve = cost_curve("inputs/challenge.txt", true);
+        assert_eq!(
+            curve.iter().min_by_key(|(_, cost)| *cost),
+            Some(&(466, 92948968))
+        );
+    }
+}
+
+/// Determine the closest common value between a set of numbers, and the overall difference between the values and the common value.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing integers to align.
+///
+/// # Returns
+///
+/// The closest common value, and the total distance of the points from the common value.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// For examples, given the numbers 16,1,2,0,4,2,7,1,2,14, the closest common value between them is 2,
+/// with a total overall difference of 37 (16 - 2 + ... + 14 - 2).
+///
+/// The total cost is accumulated as an i64, since triangular costs overflow an i32 for widely spread inputs.
+pub fn solution(input_path: &str) -> (i32, i64) {
+    cost_curve(input_path, true)
+        .into_iter()
+        .min_by_key(|(_, cost)| *cost)
+        .expect("Failed to parse population data")
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::solution;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution("inputs/example.txt"), (5, 168));
+    }
+
+    #[test]
+    fn example_lines_correct() {
+        assert_eq!(solution("inputs/example_lines.txt"), (5, 168));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt"), (466, 92948968));
+    }
+
+    #[test]
+    fn large_input_no_overflow() {
+        // Each crab moves 50000, costing 50000 * 50001 / 2 - four of them overflow an i32
+        assert_eq!(solution("inputs/example_large.txt"), (50000, 5000100000));
+    }
+}
+
+/// Determine the closest common value between a set of numbers without scanning every possible value.
+///
+/// The lowest triangular cost is always within 1/2 of the mean of the numbers, so only the values either side of
+/// the mean need to be checked.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing integers to align.
+///
+/// # Returns
+///
+/// The closest common value, and the total distance of the points from the common value.
+pub fn solution_triangular_fast(input_path: &str) -> (i32, i64) {
+    let to_align = read_positions(input_path);
+    let mean = to_align.iter().map(|v| *v as f64).sum::<f64>() / to_align.len() as f64;
+    [mean.floor() as i32, mean.ceil() as i32]
+        .into_iter()
+        .map(|val| (val, triangular_cost(&to_align, val)))
+        .min_by_key(|(_, cost)| *cost)
+        .expect("Failed to parse population data")
+}
+
+#[cfg(test)]
+mod test_solution_triangular_fast {
+    use crate::{solution, solution_triangular_fast};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution_triangular_fast("inputs/example.txt"), (5, 168));
+    }
+
+    #[test]
+    fn question_matches_scan() {
+        assert_eq!(
+            solution_triangular_fast("inputs/challenge.txt"),
+            solution("inputs/challenge.txt")
+        );
+    }
+
+    #[test]
+    fn large_input_matches_scan() {
+        assert_eq!(
+            solution_triangular_fast("inputs/example_large.txt"),
+            solution("inputs/example_large.txt")
+        );
+    }
+}
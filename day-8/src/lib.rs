@@ -0,0 +1,553 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// Errors encountered while decoding a line of seven segment encoded data.
+#[derive(Debug, PartialEq)]
+pub enum DecodeError {
+    /// A line had no pattern for one of the digits with a unique number of segments (1, 4, 7, and 8).
+    MissingDigit { line_number: usize, digit: usize },
+    /// An output pattern didn't match any of the decoded digits.
+    UnknownPattern { line_number: usize, pattern: String },
+    /// A line had no ` | ` separating its digit patterns from its output patterns.
+    MissingDelimiter { line_number: usize },
+    /// A line had no output patterns to decode.
+    NoOutputs { line_number: usize },
+    /// A line's decoded output was too large to fit in an i32.
+    OutputOverflow { line_number: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::MissingDigit { line_number, digit } => write!(
+                f,
+                "Line {} has no pattern for the digit {}",
+                line_number, digit
+            ),
+            DecodeError::UnknownPattern {
+                line_number,
+                pattern,
+            } => write!(
+                f,
+                "Line {} has an output pattern that isn't a digit: {}",
+                line_number, pattern
+            ),
+            DecodeError::MissingDelimiter { line_number } => write!(
+                f,
+                "Line {} has no \" | \" between its digits and outputs",
+                line_number
+            ),
+            DecodeError::NoOutputs { line_number } => {
+                write!(f, "Line {} has no output patterns", line_number)
+            }
+            DecodeError::OutputOverflow { line_number } => {
+                write!(f, "Line {} has an output too large to decode", line_number)
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn sort_string<S>(s: S) -> String
+where
+    S: Into<String>,
+{
+    let s = s.into();
+    let mut parts = s.trim().split("").collect::<Vec<&str>>();
+    parts.sort();
+    parts.join("")
+}
+
+fn clean_input(line: &str) -> Vec<String> {
+    line.split(" ")
+        .map(sort_string)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Split a single line into its digit patterns and its output patterns.
+fn split_line(line_number: usize, line: &str) -> Result<(&str, &str), DecodeError> {
+    line.split_once(" | ")
+        .ok_or(DecodeError::MissingDelimiter { line_number })
+}
+
+/// Count the digits in the output patterns of a single line that use a unique number of segments (1, 4, 7, and 8).
+fn count_unique_digits(outputs: &str) -> i32 {
+    clean_input(outputs)
+        .iter()
+        .filter(|o| matches!(o.len(), 2 | 3 | 4 | 7))
+        .count() as i32
+}
+
+/// Find the patterns for the digits with a unique number of segments (1, 4, 7, and 8).
+fn unique_digit_patterns(digits: &[String]) -> HashMap<usize, &String> {
+    let mut digit_map = HashMap::new();
+    for digit in digits {
+        match digit.len() {
+            2 => {
+                let _ = digit_map.insert(1, digit);
+            }
+            3 => {
+                let _ = digit_map.insert(7, digit);
+            }
+            4 => {
+                let _ = digit_map.insert(4, digit);
+            }
+            7 => {
+                let _ = digit_map.insert(8, digit);
+            }
+            _ => (),
+        }
+    }
+    digit_map
+}
+
+/// Recover which scrambled wire drives each of the seven segments from the ten unique patterns of a line.
+///
+/// # Arguments
+///
+/// * `digits - The ten unique signal patterns from the left hand side of a line.
+///
+/// # Returns
+///
+/// The wire for each segment, in segment order `a` to `g`.
+///
+/// # Panics
+///
+/// If the patterns don't include one of the digits with a unique number of segments (1, 4, 7, and 8).
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The patterns below map segments `a` to `g` onto the wires `deafgbc` - e.g. wire `d` drives the top segment:
+///
+/// ```text
+/// acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab
+/// ```
+pub fn wire_mapping(digits: &[String]) -> [char; 7] {
+    let digit_map = unique_digit_patterns(digits);
+    let pattern = |digit: usize| {
+        digit_map
+            .get(&digit)
+            .unwrap_or_else(|| panic!("No pattern for the digit {}", digit))
+    };
+
+    let mut seven_segments = ['-'; 7];
+
+    let mut counter = HashMap::new();
+    for digit in digits {
+        for c in digit.chars() {
+            counter.entry(c).and_modify(|v| *v += 1).or_insert(1);
+        }
+    }
+    // a comes from 7 - 1
+    for chr in pattern(7).chars() {
+        if !pattern(1).contains(chr) {
+            seven_segments[0] = chr
+        }
+    }
+
+    for (chr, count) in counter {
+        match count {
+            4 => seven_segments[4] = chr, // e has 9 occurrences
+            6 => seven_segments[1] = chr, // b has 9 occurrences
+            9 => seven_segments[5] = chr, // f has 9 occurrences
+            8
+                // both c and a have 8 occurrences, so choose the char that's not mapped to a already
+                if seven_segments[0] != chr => {
+                    seven_segments[2] = chr;
+                }
+            _ => (),
+        }
+    }
+
+    // We're now just missing d, g
+    //
+    // The digit definitions are as follows:
+    //
+    // 0 = a + b + c + e + f + g
+    // 1 = c + f
+    // 2 = a + c + d + e + g
+    // 3 = a + c + d + f + g
+    // 4 = b + c + d + f
+    // 5 = a + b + d + f + g
+    // 6 = a + b + d + e + f + g
+    // 7 = a + c + f
+    // 8 = a + b + c + d + e + f + g
+    // 9 = a + b + c + d + f + g
+    //
+    // We also have the following relationships:
+    //
+    // e + g = 8 - 7 - 4
+    // c + f = 1
+    // b + d = 4 - 1
+    // a = 7 - 1
+
+    // So, we can find d and g by subbing in our known values for e and b
+
+    // g = 8 - 7 - 4 - e
+    for chr in pattern(8).chars() {
+        if pattern(7).contains(chr) || pattern(4).contains(chr) || chr == seven_segments[4] {
+            continue;
+        }
+        seven_segments[6] = chr;
+    }
+
+    // d = 4 - 1 - b
+    for chr in pattern(4).chars() {
+        if pattern(1).contains(chr) || chr == seven_segments[1] {
+            continue;
+        }
+        seven_segments[3] = chr;
+    }
+
+    seven_segments
+}
+
+#[cfg(test)]
+mod test_wire_mapping {
+    use crate::{clean_input, wire_mapping};
+
+    #[test]
+    fn example_correct() {
+        let digits = clean_input("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab");
+        assert_eq!(wire_mapping(&digits), ['d', 'e', 'a', 'f', 'g', 'b', 'c']);
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_digit_fail() {
+        let digits = clean_input("cdfbe gcdfa fbcad cefabd cdfgeb eafb cagedb ab");
+        wire_mapping(&digits);
+    }
+}
+
+/// Decode the four digit output of a single line of seven segment encoded data.
+fn decode_line(line_number: usize, line: &str) -> Result<i32, DecodeError> {
+    let (digits, outputs) = split_line(line_number, line)?;
+
+    let digits: Vec<String> = clean_input(digits);
+    let outputs: Vec<String> = clean_input(outputs);
+
+    let digit_map = unique_digit_patterns(&digits);
+    // Everything else is deduced from the digits with a unique number of segments
+    if let Some(digit) = [1, 4, 7, 8]
+        .into_iter()
+        .find(|d| !digit_map.contains_key(d))
+    {
+        return Err(DecodeError::MissingDigit { line_number, digit });
+    }
+    let seven_segments = wire_mapping(&digits);
+
+    // ```
+    //  aaaa
+    // b    c
+    // b    c
+    //  dddd
+    // e    f
+    // e    f
+    //  gggg
+    // ```
+
+    // We now have all seven segments mapped successfully! Let's fill in digit_map:
+    let segments: Vec<Vec<usize>> = vec![
+        vec![0, 1, 2, 4, 5, 6],    // 0 = a + b + c + e + f + g
+        vec![2, 5],                // 1 = c + f
+        vec![0, 2, 3, 4, 6],       // 2 = a + c + d + e + g
+        vec![0, 2, 3, 5, 6],       // 3 = a + c + d + f + g
+        vec![1, 2, 3, 5],          // 4 = b + c + d + f
+        vec![0, 1, 3, 5, 6],       // 5 = a + b + d + f + g
+        vec![0, 1, 3, 4, 5, 6],    // 6 = a + b + d + e + f + g
+        vec![0, 2, 5],             // 7 = a + c + f
+        vec![0, 1, 2, 3, 4, 5, 6], // 8 = a + b + c + d + e + f + g
+        vec![0, 1, 2, 3, 5, 6],    // 9 = a + b + c + d + f + g
+    ];
+
+    let segment_strings = segments.iter().enumerate().map(|(num, seg)| {
+        let s = sort_string(seg.iter().map(|s| seven_segments[*s]).collect::<String>());
+        (num, s)
+    });
+    let mut digit_map = HashMap::new();
+    for (num, s) in segment_strings {
+        digit_map.insert(s, num);
+    }
+    // for (k, v) in digit_map {
+    //     println!("{}: {}", k, v);
+    // }
+    if outputs.is_empty() {
+        return Err(DecodeError::NoOutputs { line_number });
+    }
+    let mut decoded: i32 = 0;
+    for o in outputs {
+        let value = match digit_map.get(&o) {
+            Some(value) => *value as i32,
+            _ => {
+                return Err(DecodeError::UnknownPattern {
+                    line_number,
+                    pattern: o,
+                })
+            }
+        };
+        decoded = decoded
+            .checked_mul(10)
+            .and_then(|d| d.checked_add(value))
+            .ok_or(DecodeError::OutputOverflow { line_number })?;
+    }
+    Ok(decoded)
+}
+
+/// Split a set of results into their values, or every error if any of them failed.
+fn collect_errors<T>(results: Vec<Result<T, DecodeError>>) -> Result<Vec<T>, Vec<DecodeError>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Decode the four digit output of each line of seven segment encoded data.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the encoded data
+///
+/// # Returns
+///
+/// The decoded output of each line, in order, or every error encountered if any line failed to decode.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The line below decodes to 8394:
+///
+/// ```text
+/// be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+/// ```
+pub fn decode_all(input_path: &str) -> Result<Vec<i32>, Vec<DecodeError>> {
+    let results = get_buf_reader(input_path)
+        .lines()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            decode_line(
+                line_idx + 1,
+                &line.expect("Failed to parse line from file."),
+            )
+        })
+        .collect();
+    collect_errors(results)
+}
+
+#[cfg(test)]
+mod test_decode_all {
+    use crate::{decode_all, DecodeError};
+
+    #[test]
+    fn example_correct() {
+        let decoded = decode_all("inputs/example.txt").unwrap();
+        assert_eq!(decoded.len(), 10);
+        assert_eq!(decoded[0], 8394);
+    }
+
+    #[test]
+    fn malformed_lines_reported() {
+        assert_eq!(
+            decode_all("inputs/example_malformed.txt"),
+            Err(vec![
+                DecodeError::UnknownPattern {
+                    line_number: 2,
+                    pattern: "ab".to_string()
+                },
+                DecodeError::MissingDigit {
+                    line_number: 3,
+                    digit: 1
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn bad_outputs_reported() {
+        // An empty output side, and ten output digits
+        assert_eq!(
+            decode_all("inputs/example_bad_outputs.txt"),
+            Err(vec![
+                DecodeError::NoOutputs { line_number: 1 },
+                DecodeError::OutputOverflow { line_number: 2 },
+            ])
+        );
+    }
+
+    #[test]
+    fn missing_delimiter_reported() {
+        assert_eq!(
+            decode_all("inputs/example_no_delimiter.txt"),
+            Err(vec![DecodeError::MissingDelimiter { line_number: 2 }])
+        );
+    }
+}
+
+/// Counts the number of occurrences of digits 1, 4, 7, and 8 in an encoded input file.
+///
+/// Encoding is a random string of characters, where each group of characters represents the representation
+/// of the digit in a seven-segment display:
+///
+/// ```text
+///  aaaa    
+/// b    c
+/// b    c  
+///  dddd   
+/// e    f
+/// e    f  
+///  gggg   
+/// ```
+///
+/// E.g. cf here would represent a one.
+///
+/// The input file contains many different encodings, where a/b/c etc. are randomly mapped to a digit segment:
+///
+/// ```text
+/// be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb
+/// 1      8   9/6/0   9/6/0  4   2/3/5 9/6/0  2/3/5 2/3/5  7
+/// ```
+///
+/// This string
+/// # Arguments
+///
+/// * `input_path - The input file path containing the encoded data
+///
+/// # Returns
+///
+/// The sum of the decoded outputs, or every error encountered if any line failed to decode.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// In the following example, we see that there are 26 instances of 1, 4, 7, and 8.
+///
+/// ```text
+/// be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+/// edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+/// fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+/// fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+/// aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+/// fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+/// dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+/// bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+/// egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+/// gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+/// ```
+pub fn solution(input_path: &str) -> Result<i32, Vec<DecodeError>> {
+    Ok(decode_all(input_path)?.iter().sum())
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::solution;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solution("inputs/example.txt"), Ok(61229));
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(solution("inputs/challenge.txt"), Ok(1073431));
+    }
+}
+
+/// Count the occurrences of 1, 4, 7, and 8 in the output data, and sum the decoded outputs, in a single pass.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the encoded data
+///
+/// # Returns
+///
+/// The occurrences of 1, 4, 7, and 8 in the output data, and the sum of the decoded outputs - or every error
+/// encountered if any line failed to decode.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The example from `solution` has 26 instances of 1, 4, 7, and 8, and its outputs sum to 61229.
+pub fn solve_both(input_path: &str) -> Result<(i32, i32), Vec<DecodeError>> {
+    let mut unique_digits = 0;
+    let mut decoded = Vec::new();
+    for (line_idx, line) in get_buf_reader(input_path).lines().enumerate() {
+        let line = line.expect("Failed to parse line from file.");
+        // Lines without outputs are reported when decoding, so only need skipping here
+        if let Ok((_, outputs)) = split_line(line_idx + 1, &line) {
+            unique_digits += count_unique_digits(outputs);
+        }
+        decoded.push(decode_line(line_idx + 1, &line));
+    }
+    let output_sum = collect_errors(decoded)?.iter().sum();
+    Ok((unique_digits, output_sum))
+}
+
+#[cfg(test)]
+mod test_solve_both {
+    use crate::{solve_both, DecodeError};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(solve_both("inputs/example.txt"), Ok((26, 61229)));
+    }
+
+    #[test]
+    fn missing_delimiter_reported() {
+        assert_eq!(
+            solve_both("inputs/example_no_delimiter.txt"),
+            Err(vec![DecodeError::MissingDelimiter { line_number: 2 }])
+        );
+    }
+}
@@ -0,0 +1,453 @@
+use shared::grid::Grid;
+use shared::parse::parse_digit_line;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::num::ParseIntError;
+use std::sync::OnceLock;
+
+/// Open an input path and return a buffered reader over the contents, reading from stdin if the path is `-`.
+fn get_buf_reader(input_path: &str) -> Box<dyn BufRead> {
+    if input_path == "-" {
+        // Stdin can only be consumed once, so keep it around for solvers that read their input more than once
+        static STDIN: OnceLock<String> = OnceLock::new();
+        let contents = STDIN.get_or_init(|| {
+            std::io::read_to_string(std::io::stdin().lock()).expect("Error reading from stdin")
+        });
+        return Box::new(contents.as_bytes());
+    }
+    // Create a buffer to read the file line by line
+    let contents =
+        File::open(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    Box::new(BufReader::new(contents))
+}
+
+#[cfg(test)]
+mod test_get_buf_reader {
+    use crate::get_buf_reader;
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        get_buf_reader("inputs/noexist.txt");
+    }
+
+    #[test]
+    fn example_file_handled() {
+        get_buf_reader("inputs/example.txt");
+    }
+}
+
+/// The height that basins stop at.
+pub const DEFAULT_WALL_VALUE: i32 = 9;
+/// The number of largest basins multiplied together.
+pub const DEFAULT_BASIN_COUNT: usize = 3;
+
+/// A grid of heights, stored row by row.
+struct Field {
+    spaces: Vec<i32>,
+    width: usize,
+}
+
+impl From<Grid<i32>> for Field {
+    fn from(grid: Grid<i32>) -> Field {
+        Field {
+            spaces: grid.values,
+            width: grid.width,
+        }
+    }
+}
+impl Field {
+    /// Parse a line of heights, either as single digits or as numbers separated by commas and/or whitespace.
+    fn parse_line(line: &str, separated: bool) -> Result<Vec<i32>, ParseIntError> {
+        if !separated {
+            return parse_digit_line::<i32>(line);
+        }
+        line.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<i32>())
+            .collect()
+    }
+
+    /// Parse a field of heights from its lines, where `first_line` is the input line number of the first line.
+    fn from_lines(lines: &[String], first_line: usize, separated: bool) -> Field {
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(offset, line)| {
+                Field::parse_line(line, separated).unwrap_or_else(|_| {
+                    panic!("Failed to parse line {}: {}", first_line + offset, line)
+                })
+            })
+            .collect::<Vec<Vec<i32>>>();
+        // Use just the first line to determine the overall width of the inputs
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        Field {
+            spaces: rows.concat(),
+            width,
+        }
+    }
+
+    /// Read every field of heights from an input file, where fields are separated by blank lines.
+    fn read_fields(input_path: &str, separated: bool) -> Vec<Field> {
+        let reader = get_buf_reader(input_path);
+        let mut fields = Vec::new();
+        let mut lines = Vec::new();
+        let mut first_line = 1;
+        for (line_idx, line) in reader.lines().enumerate() {
+            let line_number = line_idx + 1;
+            let line =
+                line.unwrap_or_else(|e| panic!("Failed to parse line {}: {}", line_number, e));
+            if line.trim().is_empty() {
+                if !lines.is_empty() {
+                    fields.push(Field::from_lines(&lines, first_line, separated));
+                    lines.clear();
+                }
+                continue;
+            }
+            if lines.is_empty() {
+                first_line = line_number;
+            }
+            lines.push(line);
+        }
+        if !lines.is_empty() {
+            fields.push(Field::from_lines(&lines, first_line, separated));
+        }
+        fields
+    }
+
+    /// Read the first field of heights from an input file.
+    fn from_path(input_path: &str) -> Field {
+        Field::read_fields(input_path, false)
+            .into_iter()
+            .next()
+            .expect("No fields found in input file.")
+    }
+
+    /// Return the sum of the local minima's risk values, and the product of the N largest basin sizes.
+    fn risk_and_basin_product(&self, wall_value: i32, num_basins: usize) -> (i32, i32) {
+        // Search every point in the array for local minima
+        let risk_score = self.minima().iter().map(|idx| self.get(*idx) + 1).sum();
+        let mut basin_sizes = self
+            .basins(wall_value)
+            .iter()
+            .map(|basin| basin.len())
+            .collect::<Vec<usize>>();
+        basin_sizes.sort();
+        basin_sizes.reverse();
+        (
+            risk_score,
+            basin_sizes.iter().take(num_basins).product::<usize>() as i32,
+        )
+    }
+
+    /// Return the indexes of all local minima in the field.
+    fn minima(&self) -> Vec<usize> {
+        (0..self.len()).filter(|idx| self.is_minima(*idx)).collect()
+    }
+
+    /// Return the indexes of every point in the basin around each local minima, with basins stopping at walls.
+    fn basins(&self, wall_value: i32) -> Vec<HashSet<usize>> {
+        self.minima()
+            .iter()
+            .map(|idx| self.ascending_neighbors(*idx, wall_value))
+            .collect()
+    }
+    /// Return the count of elements in the Field.
+    fn len(&self) -> usize {
+        self.spaces.len()
+    }
+
+    /// Return the value of the field at the given index.
+    fn get(&self, idx: usize) -> i32 {
+        self.spaces[idx]
+    }
+
+    /// Return the indexes of all points adjacent to the given point.
+    fn neighbors(&self, idx: usize) -> Vec<usize> {
+        let mut neighbors = Vec::new();
+        // Check the value above us
+        if idx >= self.width {
+            neighbors.push(idx - self.width);
+        }
+        // Check the value to the left of us
+        if !idx.is_multiple_of(self.width) {
+            neighbors.push(idx - 1);
+        }
+        // Check the value to the right of us
+        if idx % self.width != self.width - 1 {
+            neighbors.push(idx + 1);
+        }
+        // Check the value below us
+        if idx < self.spaces.len() - self.width {
+            neighbors.push(idx + self.width);
+        }
+        neighbors
+    }
+
+    /// Return True if all neighbors of the index are greater than the index, False otherwise.
+    fn is_minima(&self, idx: usize) -> bool {
+        let this_val = self.spaces[idx];
+        for neighbor in self.neighbors(idx) {
+            if this_val >= self.spaces[neighbor] {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Return all neighbors of the index that are greater than the given point, up to but not including the wall value.
+    fn ascending_neighbors(&self, idx: usize, wall_value: i32) -> HashSet<usize> {
+        let mut new_neighbors = HashSet::new();
+        // Make this index a part of the neighbor set
+        new_neighbors.insert(idx);
+
+        // Check all adjacent points
+        let this_val = self.get(idx);
+        for neighbor in self.neighbors(idx) {
+            let next_val = self.get(neighbor);
+            if next_val > this_val && next_val < wall_value {
+                // This is an ascending neighbor, so add it to the set and check its neighbors as well
+                new_neighbors.extend(self.ascending_neighbors(neighbor, wall_value));
+            }
+        }
+        new_neighbors
+    }
+}
+
+#[cfg(test)]
+mod test_field {
+    use crate::{Field, DEFAULT_BASIN_COUNT, DEFAULT_WALL_VALUE};
+    use shared::grid::Grid;
+
+    fn field(grid: &str) -> Field {
+        grid.parse::<Grid<i32>>().unwrap().into()
+    }
+
+    #[test]
+    fn single_basin_correct() {
+        let field = field("1239\n2349\n9999");
+        assert_eq!(field.minima(), vec![0]);
+        assert_eq!(
+            field.risk_and_basin_product(DEFAULT_WALL_VALUE, DEFAULT_BASIN_COUNT),
+            (2, 6)
+        );
+    }
+
+    #[test]
+    fn walls_split_basins() {
+        let field = field("109\n999\n901");
+        assert_eq!(field.minima(), vec![1, 7]);
+        assert_eq!(field.basins(DEFAULT_WALL_VALUE).len(), 2);
+        assert_eq!(
+            field.risk_and_basin_product(DEFAULT_WALL_VALUE, DEFAULT_BASIN_COUNT),
+            (2, 4)
+        );
+    }
+}
+
+/// Finds all local minima in an input array of values, and returns the sum of their risk values, as well as the product of all basin sizes around the minima.
+///
+/// A local minima is any point in the array that is lower than its adjacent up, down, left, and right points.
+///
+/// A risk value is one plus the local minima value.
+///
+/// A basin is all points that lead into a local minima.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the arrays of values, separated by blank lines.
+/// * `wall_value - Basins stop at points of this value or higher, normally `DEFAULT_WALL_VALUE`.
+/// * `num_basins - The number of largest basins to multiply together, normally `DEFAULT_BASIN_COUNT`.
+/// * `separated - Read heights as numbers separated by commas and/or whitespace, rather than single digits.
+///
+/// # Returns
+///
+/// The sum of the local minima's risk values, and the product of the N largest basin sizes, for each array.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The following array has 4 local minima, with a total risk value of 15:
+/// ```text
+/// 2199943210 // width 10
+/// 3987894921
+/// 9856789892
+/// 8767896789
+/// 9899965678
+/// ```
+pub fn solution(
+    input_path: &str,
+    wall_value: i32,
+    num_basins: usize,
+    separated: bool,
+) -> Vec<(i32, i32)> {
+    Field::read_fields(input_path, separated)
+        .iter()
+        .map(|field| field.risk_and_basin_product(wall_value, num_basins))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::{solution, DEFAULT_BASIN_COUNT, DEFAULT_WALL_VALUE};
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            solution(
+                "inputs/example.txt",
+                DEFAULT_WALL_VALUE,
+                DEFAULT_BASIN_COUNT,
+                false
+            ),
+            vec![(15, 1134)]
+        );
+    }
+
+    #[test]
+    fn example_multiple_correct() {
+        assert_eq!(
+            solution(
+                "inputs/example_multiple.txt",
+                DEFAULT_WALL_VALUE,
+                DEFAULT_BASIN_COUNT,
+                false
+            ),
+            vec![(15, 1134), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn question_correct() {
+        assert_eq!(
+            solution(
+                "inputs/challenge.txt",
+                DEFAULT_WALL_VALUE,
+                DEFAULT_BASIN_COUNT,
+                false
+            ),
+            vec![(580, 856716)]
+        );
+    }
+
+    #[test]
+    fn example_basin_count_correct() {
+        // Basin sizes are 3, 9, 14, and 9
+        assert_eq!(
+            solution("inputs/example.txt", DEFAULT_WALL_VALUE, 2, false),
+            vec![(15, 14 * 9)]
+        );
+        assert_eq!(
+            solution("inputs/example.txt", DEFAULT_WALL_VALUE, 4, false),
+            vec![(15, 1134 * 3)]
+        );
+    }
+
+    #[test]
+    fn example_two_digit_correct() {
+        // The example with each height h as 10h + 5, so the minima's risk values are each 10h + 6
+        assert_eq!(
+            solution(
+                "inputs/example_two_digit.txt",
+                90,
+                DEFAULT_BASIN_COUNT,
+                true
+            ),
+            vec![(134, 1134)]
+        );
+    }
+}
+
+/// Finds all local minima in the first input array of values, and returns their coordinates.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the array of values.
+///
+/// # Returns
+///
+/// The (x, y) coordinates of each local minima, ordered row by row, with 0, 0 being the top left.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// The example array from `solution` has local minima at (1, 0), (9, 0), (2, 2), and (6, 4).
+pub fn minima_coords(input_path: &str) -> Vec<(usize, usize)> {
+    let field = Field::from_path(input_path);
+    field
+        .minima()
+        .iter()
+        .map(|idx| (idx % field.width, idx / field.width))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_minima_coords {
+    use crate::minima_coords;
+
+    #[test]
+    fn example_correct() {
+        assert_eq!(
+            minima_coords("inputs/example.txt"),
+            vec![(1, 0), (9, 0), (2, 2), (6, 4)]
+        );
+    }
+}
+
+/// Finds the basin around every local minima in the first input array of values.
+///
+/// # Arguments
+///
+/// * `input_path - The input file path containing the array of values.
+/// * `wall_value - Basins stop at points of this value or higher, normally `DEFAULT_WALL_VALUE`.
+///
+/// # Returns
+///
+/// The indexes (row by row, with 0 being the top left) of every point in each basin, ordered the same as
+/// `minima_coords`.
+pub fn basins(input_path: &str, wall_value: i32) -> Vec<HashSet<usize>> {
+    Field::from_path(input_path).basins(wall_value)
+}
+
+#[cfg(test)]
+mod test_basins {
+    use crate::{basins, DEFAULT_WALL_VALUE};
+    use std::collections::HashSet;
+
+    #[test]
+    fn example_covers_grid() {
+        let heights = std::fs::read_to_string("inputs/example.txt")
+            .unwrap()
+            .lines()
+            .flat_map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<char>>();
+        let mut covered = basins("inputs/example.txt", DEFAULT_WALL_VALUE)
+            .into_iter()
+            .flatten()
+            .collect::<HashSet<usize>>();
+        covered.extend((0..heights.len()).filter(|idx| heights[*idx] == '9'));
+        assert_eq!(covered, (0..heights.len()).collect::<HashSet<usize>>());
+    }
+
+    #[test]
+    fn example_sizes_correct() {
+        let sizes = basins("inputs/example.txt", DEFAULT_WALL_VALUE)
+            .iter()
+            .map(|basin| basin.len())
+            .collect::<Vec<usize>>();
+        assert_eq!(sizes, vec![3, 9, 14, 9]);
+    }
+
+    #[test]
+    fn example_custom_wall_sizes() {
+        // Walls of 8 cut the 8s out of the last two basins
+        let sizes = basins("inputs/example.txt", 8)
+            .iter()
+            .map(|basin| basin.len())
+            .collect::<Vec<usize>>();
+        assert_eq!(sizes, vec![3, 9, 7, 6]);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fs::read_to_string;
+use std::str::FromStr;
+
+/// Read a golden answers file, keyed by day and part.
+///
+/// Each line is of the form `<day directory> part <part>: <answer>`, where blank lines and lines starting with `#`
+/// are ignored.
+///
+/// # Arguments
+///
+/// * `input_path` - The path of the golden answers file.
+///
+/// # Returns
+///
+/// The expected answer for each (day directory, part).
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// ```text
+/// # Comments are skipped
+/// day-1 part 1: 1446
+/// day-1 part 2: 1486
+/// day-7 part 2: 92948968
+/// ```
+///
+/// Gives answers for both parts of `day-1`, and for part 2 of `day-7`.
+pub fn read_answers(input_path: &str) -> BTreeMap<(String, usize), String> {
+    let contents =
+        read_to_string(input_path).unwrap_or_else(|_| panic!("Error reading file: {}", input_path));
+    let mut answers = BTreeMap::new();
+    for (line_idx, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let parsed = line.split_once(": ").and_then(|(key, answer)| {
+            let (day, part) = key.split_once(" part ")?;
+            Some((day.to_string(), part.parse::<usize>().ok()?, answer))
+        });
+        let (day, part, answer) =
+            parsed.unwrap_or_else(|| panic!("Failed to parse line {}: {}", line_idx + 1, line));
+        answers.insert((day, part), answer.to_string());
+    }
+    answers
+}
+
+/// Look up the golden answer for a part of a day, parsed as the type that day's solver returns.
+///
+/// # Arguments
+///
+/// * `answers` - The golden answers, as read by `read_answers`.
+/// * `day` - The day directory to look up.
+/// * `part` - The part of the day to look up.
+///
+/// # Returns
+///
+/// The parsed answer - panics if there is no answer, or it can't be parsed.
+pub fn expected<T>(answers: &BTreeMap<(String, usize), String>, day: &str, part: usize) -> T
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    let answer = answers
+        .get(&(day.to_string(), part))
+        .unwrap_or_else(|| panic!("No golden answer for {} part {}", day, part));
+    answer
+        .parse()
+        .unwrap_or_else(|e| panic!("Failed to parse {} part {}: {:?}", day, part, e))
+}
+
+#[cfg(test)]
+mod test_read_answers {
+    use crate::{expected, read_answers};
+
+    #[test]
+    fn answers_keyed_by_day_and_part() {
+        let answers = read_answers("answers.txt");
+        assert_eq!(answers[&("day-1".to_string(), 1)], "1446");
+        assert_eq!(answers[&("day-1".to_string(), 2)], "1486");
+        assert_eq!(answers.len(), 29);
+    }
+
+    #[test]
+    fn expected_parsed() {
+        let answers = read_answers("answers.txt");
+        assert_eq!(expected::<u128>(&answers, "day-10", 2), 4245130838);
+    }
+
+    #[test]
+    #[should_panic(expected = "No golden answer for day-7 part 1")]
+    fn missing_answer_fail() {
+        expected::<i64>(&read_answers("answers.txt"), "day-7", 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_file_handled() {
+        read_answers("noexist.txt");
+    }
+}
@@ -0,0 +1,177 @@
+use golden::{expected, read_answers};
+use std::collections::BTreeMap;
+use std::fs::read_dir;
+
+/// Return the path of a day's challenge input, relative to this directory.
+fn challenge(day: &str) -> String {
+    format!("../{}/inputs/challenge.txt", day)
+}
+
+/// Read the golden answers file shared by every test.
+fn answers() -> BTreeMap<(String, usize), String> {
+    read_answers("answers.txt")
+}
+
+#[test]
+fn every_day_has_answers() {
+    let answers = answers();
+    for entry in read_dir("..").expect("Failed to list days.") {
+        let day = entry.unwrap().file_name().into_string().unwrap();
+        if day.starts_with("day-") {
+            assert!(
+                answers.keys().any(|(answer_day, _)| answer_day == &day),
+                "No golden answers for {}",
+                day
+            );
+        }
+    }
+}
+
+#[test]
+fn day_1() {
+    let answers = answers();
+    let (part_1, part_2) = day_1::count_both(&challenge("day-1"));
+    assert_eq!(part_1, expected(&answers, "day-1", 1));
+    assert_eq!(part_2, expected(&answers, "day-1", 2));
+}
+
+#[test]
+fn day_2() {
+    let answers = answers();
+    let (x, y) = day_2::record_movements(&challenge("day-2"));
+    assert_eq!(x * y, expected(&answers, "day-2", 1));
+    let (x, y) = day_2::record_movements_with_aim(&challenge("day-2"));
+    assert_eq!(x * y, expected(&answers, "day-2", 2));
+}
+
+#[test]
+fn day_3() {
+    let answers = answers();
+    let (x, y) = day_3::read_power_report(&challenge("day-3"));
+    assert_eq!(x * y, expected(&answers, "day-3", 1));
+    let (x, y) = day_3::read_life_support_report(&challenge("day-3"));
+    assert_eq!(x * y, expected(&answers, "day-3", 2));
+}
+
+#[test]
+fn day_4() {
+    let answers = answers();
+    let result = day_4::solution(&challenge("day-4"));
+    assert_eq!(result.best_score, expected(&answers, "day-4", 1));
+    assert_eq!(result.worst_score, expected(&answers, "day-4", 2));
+}
+
+#[test]
+fn day_5() {
+    let answers = answers();
+    let overlaps = |ignore_diagonal| {
+        day_5::solution(
+            &challenge("day-5"),
+            ignore_diagonal,
+            day_5::DEFAULT_MIN_OVERLAP,
+            false,
+            day_5::DEFAULT_MAX_DIM,
+        )
+    };
+    assert_eq!(overlaps(true), expected(&answers, "day-5", 1));
+    assert_eq!(overlaps(false), expected(&answers, "day-5", 2));
+}
+
+#[test]
+fn day_6() {
+    let answers = answers();
+    let population = |days| {
+        day_6::solution(
+            &challenge("day-6"),
+            days,
+            day_6::NEW_FISH_TTR,
+            day_6::OLD_FISH_TTR,
+        )
+    };
+    assert_eq!(population(80), Ok(expected(&answers, "day-6", 1)));
+    assert_eq!(population(256), Ok(expected(&answers, "day-6", 2)));
+}
+
+#[test]
+fn day_7() {
+    let (_, distance) = day_7::solution(&challenge("day-7"));
+    assert_eq!(distance, expected(&answers(), "day-7", 2));
+}
+
+#[test]
+fn day_8() {
+    let answers = answers();
+    let (unique_digits, output_sum) = day_8::solve_both(&challenge("day-8")).unwrap();
+    assert_eq!(unique_digits, expected(&answers, "day-8", 1));
+    assert_eq!(output_sum, expected(&answers, "day-8", 2));
+}
+
+#[test]
+fn day_9() {
+    let answers = answers();
+    let (risk, basins) = day_9::solution(
+        &challenge("day-9"),
+        day_9::DEFAULT_WALL_VALUE,
+        day_9::DEFAULT_BASIN_COUNT,
+        false,
+    )[0];
+    assert_eq!(risk, expected(&answers, "day-9", 1));
+    assert_eq!(basins, expected(&answers, "day-9", 2));
+}
+
+#[test]
+fn day_10() {
+    let answers = answers();
+    let (syntax_score, incomplete_score) = day_10::solution(&challenge("day-10")).unwrap();
+    assert_eq!(syntax_score, expected(&answers, "day-10", 1));
+    assert_eq!(incomplete_score, expected(&answers, "day-10", 2));
+}
+
+#[test]
+fn day_11() {
+    let answers = answers();
+    let (activation_count, sync_step) = day_11::solution(
+        &challenge("day-11"),
+        100,
+        day_11::DEFAULT_ACTIVATION_ENERGY,
+        day_11::DEFAULT_SYNC_BUDGET,
+    );
+    assert_eq!(activation_count, expected(&answers, "day-11", 1));
+    assert_eq!(sync_step, Some(expected(&answers, "day-11", 2)));
+}
+
+#[test]
+fn day_12() {
+    let paths = day_12::solution(&challenge("day-12"), &[], None);
+    assert_eq!(paths, Ok(expected(&answers(), "day-12", 2)));
+}
+
+#[test]
+fn day_13() {
+    let answers = answers();
+    let points = |folds| day_13::solution(&challenge("day-13"), folds, None);
+    assert_eq!(points(1), Ok(expected(&answers, "day-13", 1)));
+    assert_eq!(points(0), Ok(expected(&answers, "day-13", 2)));
+}
+
+#[test]
+fn day_14() {
+    let answers = answers();
+    let spread = |steps| day_14::solution(&challenge("day-14"), steps);
+    assert_eq!(spread(10), expected(&answers, "day-14", 1));
+    assert_eq!(spread(40), expected(&answers, "day-14", 2));
+}
+
+#[test]
+fn day_15() {
+    let answers = answers();
+    let costs = day_15::solutions(&challenge("day-15"), &[1, 5]);
+    assert_eq!(costs[0], expected(&answers, "day-15", 1));
+    assert_eq!(costs[1], expected(&answers, "day-15", 2));
+}
+
+#[test]
+fn day_16() {
+    let values = day_16::solution(&challenge("day-16"));
+    assert_eq!(values, vec![expected(&answers(), "day-16", 2)]);
+}
@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors encountered while parsing command line arguments.
+#[derive(Debug, PartialEq)]
+pub enum ArgError {
+    /// A flag was passed without the value that should follow it.
+    MissingValue(String),
+    /// A part was asked for that the day doesn't solve.
+    UnknownPart { part: String, solved: Vec<usize> },
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgError::MissingValue(flag) => write!(f, "Expected a value after {}", flag),
+            ArgError::UnknownPart { part, solved } => {
+                write!(f, "Unknown part: {}, expected one of {:?}", part, solved)
+            }
+        }
+    }
+}
+
+impl Error for ArgError {}
+
+/// Split an optional `--part` flag out of the command line arguments.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments
+/// * `solved` - the parts the day solves, which `--part` must be one of
+///
+/// # Returns
+///
+/// The remaining command line arguments, and the part to run where None runs every solved part - or an error if the
+/// part isn't one of `solved`.
+pub fn parse_part(
+    args: &[String],
+    solved: &[usize],
+) -> Result<(Vec<String>, Option<usize>), ArgError> {
+    let mut remaining = args.to_vec();
+    let flag_idx = match remaining.iter().position(|arg| arg == "--part") {
+        Some(idx) => idx,
+        None => return Ok((remaining, None)),
+    };
+    let part = remaining
+        .get(flag_idx + 1)
+        .ok_or_else(|| ArgError::MissingValue("--part".to_string()))?;
+    let part = match part.parse::<usize>() {
+        Ok(part) if solved.contains(&part) => part,
+        _ => {
+            return Err(ArgError::UnknownPart {
+                part: part.clone(),
+                solved: solved.to_vec(),
+            })
+        }
+    };
+    remaining.drain(flag_idx..flag_idx + 2);
+    Ok((remaining, Some(part)))
+}
+
+#[cfg(test)]
+mod test_parse_part {
+    use crate::args::{parse_part, ArgError};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_runs_both() {
+        assert_eq!(
+            parse_part(&args(&["script_path", "arg_text"]), &[1, 2]),
+            Ok((args(&["script_path", "arg_text"]), None))
+        );
+    }
+
+    #[test]
+    fn part_1_ok() {
+        assert_eq!(
+            parse_part(&args(&["script_path", "arg_text", "--part", "1"]), &[1, 2]),
+            Ok((args(&["script_path", "arg_text"]), Some(1)))
+        );
+    }
+
+    #[test]
+    fn part_2_ok() {
+        assert_eq!(
+            parse_part(&args(&["script_path", "--part", "2", "arg_text"]), &[1, 2]),
+            Ok((args(&["script_path", "arg_text"]), Some(2)))
+        );
+    }
+
+    #[test]
+    fn unknown_part_fail() {
+        assert_eq!(
+            parse_part(&args(&["script_path", "arg_text", "--part", "3"]), &[1, 2]),
+            Err(ArgError::UnknownPart {
+                part: "3".to_string(),
+                solved: vec![1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn unsolved_part_fail() {
+        let err = parse_part(&args(&["script_path", "arg_text", "--part", "1"]), &[2]).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown part: 1, expected one of [2]");
+    }
+
+    #[test]
+    fn missing_part_fail() {
+        assert_eq!(
+            parse_part(&args(&["script_path", "arg_text", "--part"]), &[1, 2]),
+            Err(ArgError::MissingValue("--part".to_string()))
+        );
+    }
+}
+
+/// Split an optional `--time` flag out of the command line arguments.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments
+///
+/// # Returns
+///
+/// The remaining command line arguments, and whether to print how long solving took.
+pub fn parse_time(args: &[String]) -> (Vec<String>, bool) {
+    let mut remaining = args.to_vec();
+    match remaining.iter().position(|arg| arg == "--time") {
+        Some(idx) => {
+            remaining.remove(idx);
+            (remaining, true)
+        }
+        None => (remaining, false),
+    }
+}
+
+#[cfg(test)]
+mod test_parse_time {
+    use crate::args::parse_time;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_untimed() {
+        assert_eq!(
+            parse_time(&args(&["script_path", "arg_text"])),
+            (args(&["script_path", "arg_text"]), false)
+        );
+    }
+
+    #[test]
+    fn time_ok() {
+        assert_eq!(
+            parse_time(&args(&["script_path", "--time", "arg_text"])),
+            (args(&["script_path", "arg_text"]), true)
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use crate::parse::parse_digit_line;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A rectangular grid of values, stored row by row.
+#[derive(Debug, PartialEq)]
+pub struct Grid<T> {
+    pub values: Vec<T>,
+    pub width: usize,
+}
+
+/// Errors encountered while parsing a grid.
+#[derive(Debug, PartialEq)]
+pub enum GridError {
+    /// A line (numbered from 1) contained something other than single digits.
+    InvalidLine(usize),
+    /// A line (numbered from 1) was a different width to the first line.
+    RaggedLine(usize),
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::InvalidLine(line) => write!(f, "Line {} isn't made up of digits", line),
+            GridError::RaggedLine(line) => {
+                write!(f, "Line {} is a different width to the first line", line)
+            }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl<T: FromStr> FromStr for Grid<T> {
+    type Err = GridError;
+
+    /// Parse a grid from lines of single digits, such as `123\n456\n789`, inferring the width from the first line.
+    fn from_str(s: &str) -> Result<Grid<T>, GridError> {
+        let mut values = Vec::new();
+        let mut width = 0;
+        for (line_idx, line) in s.lines().map(|line| line.trim()).enumerate() {
+            let row =
+                parse_digit_line::<T>(line).map_err(|_| GridError::InvalidLine(line_idx + 1))?;
+            if line_idx == 0 {
+                width = row.len();
+            } else if row.len() != width {
+                return Err(GridError::RaggedLine(line_idx + 1));
+            }
+            values.extend(row);
+        }
+        Ok(Grid { values, width })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Render the grid as lines of values, one row per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.values.chunks(self.width.max(1)) {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_grid {
+    use crate::grid::{Grid, GridError};
+
+    #[test]
+    fn round_trip() {
+        let grid = "123\n456\n789\n".parse::<Grid<usize>>().unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.values, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(grid.to_string(), "123\n456\n789\n");
+    }
+
+    #[test]
+    fn invalid_line_fail() {
+        assert_eq!(
+            "123\n4x6".parse::<Grid<usize>>(),
+            Err(GridError::InvalidLine(2))
+        );
+    }
+
+    #[test]
+    fn ragged_line_fail() {
+        assert_eq!(
+            "123\n45\n789".parse::<Grid<usize>>(),
+            Err(GridError::RaggedLine(2))
+        );
+    }
+}
+
+/// A vertex waiting to be visited, ordered so that the closest vertex is popped first from a max heap.
+#[derive(Debug)]
+struct Visit<V> {
+    vertex: V,
+    distance: usize,
+}
+
+impl<V> Ord for Visit<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl<V> PartialOrd for Visit<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> PartialEq for Visit<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance.eq(&other.distance)
+    }
+}
+
+impl<V> Eq for Visit<V> {}
+
+/// Find the cost of the lowest cost path between two vertices of a graph using Dijkstra's algorithm.
+///
+/// # Arguments
+///
+/// * `start` - The vertex to start searching from.
+/// * `neighbors` - Returns the vertices that can be moved to from a vertex.
+/// * `cost` - Returns the cost of moving into a vertex - the start vertex is never charged.
+/// * `goal` - The vertex to find a path to.
+///
+/// # Returns
+///
+/// The cost of the lowest cost path, or None if the goal can't be reached from the start.
+pub fn dijkstra<V, N, I, C>(start: V, neighbors: N, cost: C, goal: V) -> Option<usize>
+where
+    V: Copy + Eq + Hash,
+    N: Fn(V) -> I,
+    I: IntoIterator<Item = V>,
+    C: Fn(V) -> usize,
+{
+    let mut distances = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut to_visit = BinaryHeap::new();
+
+    distances.insert(start, 0);
+    to_visit.push(Visit {
+        vertex: start,
+        distance: 0,
+    });
+
+    while let Some(Visit { vertex, distance }) = to_visit.pop() {
+        if vertex == goal {
+            return Some(distance);
+        }
+        if !visited.insert(vertex) {
+            // Already visited this node
+            continue;
+        }
+
+        for neighbor in neighbors(vertex) {
+            let new_distance = distance + cost(neighbor);
+            let is_shorter = distances
+                .get(&neighbor)
+                .is_none_or(|&current| new_distance < current);
+
+            if is_shorter {
+                distances.insert(neighbor, new_distance);
+                to_visit.push(Visit {
+                    vertex: neighbor,
+                    distance: new_distance,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Find the cost of the lowest cost path from a vertex to every vertex reachable from it using Dijkstra's algorithm.
+///
+/// Works as `dijkstra`, but searches the whole graph rather than stopping at a goal.
+///
+/// # Arguments
+///
+/// * `start` - The vertex to start searching from.
+/// * `neighbors` - Returns the vertices that can be moved to from a vertex.
+/// * `cost` - Returns the cost of moving into a vertex - the start vertex is never charged.
+///
+/// # Returns
+///
+/// The cost of the lowest cost path to each vertex - vertices that can't be reached from the start are left out.
+pub fn dijkstra_distances<V, N, I, C>(start: V, neighbors: N, cost: C) -> HashMap<V, usize>
+where
+    V: Copy + Eq + Hash,
+    N: Fn(V) -> I,
+    I: IntoIterator<Item = V>,
+    C: Fn(V) -> usize,
+{
+    let mut distances = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut to_visit = BinaryHeap::new();
+
+    distances.insert(start, 0);
+    to_visit.push(Visit {
+        vertex: start,
+        distance: 0,
+    });
+
+    while let Some(Visit { vertex, distance }) = to_visit.pop() {
+        if !visited.insert(vertex) {
+            // Already visited this node
+            continue;
+        }
+
+        for neighbor in neighbors(vertex) {
+            let new_distance = distance + cost(neighbor);
+            let is_shorter = distances
+                .get(&neighbor)
+                .is_none_or(|&current| new_distance < current);
+
+            if is_shorter {
+                distances.insert(neighbor, new_distance);
+                to_visit.push(Visit {
+                    vertex: neighbor,
+                    distance: new_distance,
+                });
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod test_dijkstra {
+    use crate::grid::{dijkstra, dijkstra_distances};
+    use std::collections::HashMap;
+
+    // a --1--> b --1--> d
+    //  \               ^
+    //   ----5--> c --1-
+    //
+    // e is disconnected
+    fn neighbors(vertex: char) -> Vec<char> {
+        match vertex {
+            'a' => vec!['b', 'c'],
+            'b' => vec!['d'],
+            'c' => vec!['d'],
+            _ => vec![],
+        }
+    }
+
+    fn cost(vertex: char) -> usize {
+        match vertex {
+            'c' => 5,
+            _ => 1,
+        }
+    }
+
+    #[test]
+    fn cheapest_path_found() {
+        assert_eq!(dijkstra('a', neighbors, cost, 'd'), Some(2));
+        assert_eq!(dijkstra('c', neighbors, cost, 'd'), Some(1));
+    }
+
+    #[test]
+    fn start_is_free() {
+        assert_eq!(dijkstra('a', neighbors, cost, 'a'), Some(0));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        assert_eq!(dijkstra('a', neighbors, cost, 'e'), None);
+        assert_eq!(dijkstra('d', neighbors, cost, 'a'), None);
+    }
+
+    #[test]
+    fn all_distances_found() {
+        assert_eq!(
+            dijkstra_distances('a', neighbors, cost),
+            HashMap::from([('a', 0), ('b', 1), ('c', 5), ('d', 2)])
+        );
+        assert_eq!(
+            dijkstra_distances('c', neighbors, cost),
+            HashMap::from([('c', 0), ('d', 1)])
+        );
+    }
+}
@@ -0,0 +1,6 @@
+//! Helpers shared between the solutions for each day.
+
+pub mod args;
+pub mod grid;
+pub mod parse;
+pub mod solver;
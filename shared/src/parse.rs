@@ -0,0 +1,33 @@
+use std::str::FromStr;
+
+/// Parse a line of single digit values, such as `2199943210`, into one value per character.
+///
+/// # Arguments
+///
+/// * `line` - The line of digits to parse.
+///
+/// # Returns
+///
+/// The parsed value of each character, or the first error encountered.
+pub fn parse_digit_line<T: FromStr>(line: &str) -> Result<Vec<T>, T::Err> {
+    line.chars().map(|c| c.to_string().parse::<T>()).collect()
+}
+
+#[cfg(test)]
+mod test_parse_digit_line {
+    use crate::parse::parse_digit_line;
+
+    #[test]
+    fn digits_parsed() {
+        assert_eq!(
+            parse_digit_line::<usize>("2199943210"),
+            Ok(vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0])
+        );
+        assert_eq!(parse_digit_line::<i32>(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn non_digit_fail() {
+        assert!(parse_digit_line::<usize>("21x9").is_err());
+    }
+}
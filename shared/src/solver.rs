@@ -0,0 +1,8 @@
+/// A uniform interface to both parts of a day's puzzle, so every day can be run and checked the same way.
+pub trait Solver {
+    /// Solve the first part of the puzzle for the input at `input_path`, where `-` reads from stdin.
+    fn part1(&self, input_path: &str) -> String;
+
+    /// Solve the second part of the puzzle for the input at `input_path`, where `-` reads from stdin.
+    fn part2(&self, input_path: &str) -> String;
+}